@@ -2,9 +2,16 @@ use crate::winutils::WinUtils;
 use ahash::AHashMap;
 use atomic_float::AtomicF32;
 use atomic_refcell::AtomicRefCell;
-use std::sync::{
-    atomic::{AtomicBool, AtomicI64},
-    LazyLock,
+use parking_lot::Mutex;
+use std::{
+    fs::File,
+    io::BufWriter,
+    sync::{
+        atomic::{AtomicBool, AtomicI64, AtomicU32, AtomicUsize},
+        mpsc::Sender,
+        LazyLock, OnceLock,
+    },
+    thread::{JoinHandle, ThreadId},
 };
 use windows::Win32::System::Diagnostics::ToolHelp::MODULEENTRY32;
 use zstring::ZString;
@@ -24,9 +31,64 @@ pub static MODULES: LazyLock<AHashMap<String, SafeMODULEENTRY32>> =
 /// Logged screen (and stdout) messages.
 pub static LOGGED_MESSAGES: LazyLock<AtomicRefCell<ZString>> = LazyLock::new(Default::default);
 
+/// File which logged messages are also written to, set from config.jsonc -> log_file.
+pub static LOG_FILE: OnceLock<Mutex<BufWriter<File>>> = OnceLock::new();
+
+/// Should logged messages be prefixed with the current time? Set from config.jsonc ->
+/// log_timestamps.
+pub static LOG_TIMESTAMPS: AtomicBool = AtomicBool::new(false);
+
 /// Last-set delta time.
 pub static DELTA_TIME: AtomicF32 = AtomicF32::new(0.0);
 
+/// Playback speed of animated GIFs in frames per second, set from config.jsonc -> gif_fps.
+pub static GIF_FPS: AtomicF32 = AtomicF32::new(10.0);
+
+/// Max amount of textures uploaded to the GPU per frame, set from config.jsonc ->
+/// max_texture_loads_per_frame.
+pub static MAX_TEXTURE_LOADS_PER_FRAME: AtomicUsize = AtomicUsize::new(2);
+
+/// Last-set mouse wheel delta.
+pub static SCROLL_DELTA: AtomicF32 = AtomicF32::new(0.0);
+
+/// Software cursor color, set from config.jsonc -> cursor_color.
+pub static SOFTWARE_CURSOR_COLOR: OnceLock<[f32; 4]> = OnceLock::new();
+
+/// Software cursor size, set from config.jsonc -> cursor_size.
+pub static SOFTWARE_CURSOR_SIZE: AtomicF32 = AtomicF32::new(5.0);
+
+/// Side message position, set from config.jsonc -> side_message_pos.
+pub static SIDE_MESSAGE_POS: OnceLock<[f32; 2]> = OnceLock::new();
+
+/// Side message color, set from config.jsonc -> side_message_color.
+pub static SIDE_MESSAGE_COLOR: OnceLock<[f32; 4]> = OnceLock::new();
+
+/// Max amount of side message lines before they're cleared, set from config.jsonc ->
+/// side_message_max_lines.
+pub static SIDE_MESSAGE_MAX_LINES: AtomicUsize = AtomicUsize::new(30);
+
+/// Zlib compression level used for outgoing CrossCom data, set from config.jsonc ->
+/// compression_level.
+pub static COMPRESSION_LEVEL: AtomicU32 = AtomicU32::new(1);
+
+/// Is dynamic unloading itself through `unload`?
+/// Worker threads check this and return once it's `true`.
+pub static IS_UNLOADING: AtomicBool = AtomicBool::new(false);
+
+/// Long-running threads spawned through `WinUtils::spawn_worker`, joined before dynamic unloads.
+pub static WORKER_THREADS: Mutex<Vec<JoinHandle<()>>> = Mutex::new(Vec::new());
+
+/// Is the console command loop running? If so, it's the only one reading from `stdin`.
+pub static IS_CONSOLE_LOOP_ACTIVE: AtomicBool = AtomicBool::new(false);
+
+/// Set while a script waits on `dynamic::console_prompt`, the console command loop sends the next
+/// line through it instead of running it as a command.
+pub static CONSOLE_PROMPT_SENDER: Mutex<Option<Sender<String>>> = Mutex::new(None);
+
+/// Id of the thread rendering the UI, set on the first rendered frame.
+/// Functions blocking on the UI use it to refuse running on the render thread.
+pub static RENDER_THREAD_ID: OnceLock<ThreadId> = OnceLock::new();
+
 /// Is the cursor inside of an UI window?
 /// Global because tracking this across Rune will turn into a mess.
 pub static IS_CURSOR_IN_UI: AtomicBool = AtomicBool::new(false);
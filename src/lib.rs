@@ -20,9 +20,10 @@ mod utils;
 mod winutils;
 
 use crate::{
+    globals::{CONSOLE_PROMPT_SENDER, IS_CONSOLE_LOOP_ACTIVE, IS_UNLOADING},
     mod_cores::base_core::BaseCore,
     ui::unknown::dx11_ui::DX11UI,
-    utils::prompter::Prompter,
+    utils::{generichooks::GenericHoooks, prompter::Prompter, runedetour::RDetour},
     winutils::{Renderer, WinUtils},
 };
 use hudhook::{
@@ -33,16 +34,29 @@ use hudhook::{
     Hudhook,
 };
 use parking_lot::RwLock;
-use std::{ffi::c_void, io::IsTerminal, sync::Arc};
+use std::{
+    ffi::c_void,
+    io::IsTerminal,
+    sync::{
+        atomic::{AtomicBool, AtomicIsize, Ordering},
+        Arc,
+    },
+};
 use windows::Win32::{
     Foundation::HMODULE,
     System::{
         Console::{AllocConsole, GetConsoleWindow},
-        LibraryLoader::DisableThreadLibraryCalls,
+        LibraryLoader::{DisableThreadLibraryCalls, FreeLibraryAndExitThread},
     },
 };
 use zstring::ZString;
 
+/// Module handle of dynamic, set once injected.
+static DLL_MODULE: AtomicIsize = AtomicIsize::new(0);
+
+/// Has the UI been hooked by Hudhook?
+static IS_UI_HOOKED: AtomicBool = AtomicBool::new(false);
+
 /// Called when the DLL has been injected/detached.
 #[unsafe(no_mangle)]
 #[allow(non_snake_case, unused_variables)]
@@ -52,18 +66,59 @@ extern "system" fn DllMain(dll_module: isize, call_reason: u32, reserved: *const
 
     match call_reason {
         DLL_PROCESS_ATTACH => {
+            DLL_MODULE.store(dll_module, Ordering::Relaxed);
             let _ = unsafe { DisableThreadLibraryCalls(HMODULE(dll_module)) };
-            std::thread::spawn(move || {
+            WinUtils::spawn_worker(move || {
                 hook(dll_module);
             });
         }
-        DLL_PROCESS_DETACH => std::process::exit(0),
+        DLL_PROCESS_DETACH if !IS_UNLOADING.load(Ordering::Relaxed) => std::process::exit(0),
         _ => (),
     }
 
     1
 }
 
+/// Unloads dynamic from the process without closing it.
+/// The teardown runs on its own thread, so that the caller (usually a script) gets to return
+/// before anything is removed.
+///
+/// Worker threads are signalled and joined, then all RDetours are dropped and the UI hooks are
+/// removed before the DLL is freed. Scripts which never return will keep dynamic from unloading.
+pub fn unload(base_core: Arc<RwLock<BaseCore>>) {
+    if IS_UNLOADING.swap(true, Ordering::Relaxed) {
+        log!("[WARN] dynamic is already unloading!");
+        return;
+    }
+
+    log!("Unloading dynamic...");
+    CONSOLE_PROMPT_SENDER.lock().take();
+    std::thread::spawn(move || {
+        let base_core_reader = base_core.read();
+        base_core_reader.get_script_core().stop_workers();
+        base_core_reader.get_crosscom().read().stop();
+        drop(base_core_reader);
+        drop(base_core);
+
+        log!("Waiting for worker threads to finish...");
+        WinUtils::join_workers();
+
+        RDetour::drop_all_rdetours();
+        GenericHoooks::unhook_all();
+
+        // Hudhook unhooks, frees the console and frees the library on its own.
+        if IS_UI_HOOKED.load(Ordering::Relaxed) {
+            hudhook::eject();
+            return;
+        }
+
+        unsafe {
+            let _ = FreeConsole();
+            FreeLibraryAndExitThread(HMODULE(DLL_MODULE.load(Ordering::Relaxed)), 0);
+        }
+    });
+}
+
 /// Begins initializing and hooking everything.
 fn hook(hmodule: isize) {
     // Allocate a console window.
@@ -97,20 +152,37 @@ fn hook(hmodule: isize) {
             }
         } else {
             let base_core_clone = Arc::clone(&base_core);
-            std::thread::spawn(move || unsafe {
+            IS_CONSOLE_LOOP_ACTIVE.store(true, Ordering::Relaxed);
+            WinUtils::spawn_worker(move || unsafe {
                 // No freeing the console, we are free to listen for additional commands.
                 let mut prompt = Prompter::new_any_response(
-                    "Commands:\n» free_console\n» execute_script [relative_path (String)]\n» exit",
+                    "Commands:\n» free_console\n» execute_script [relative_path (String)]\n» list_plugins\n» eject_plugin [name (String)]\n» exit",
                 );
                 while GetConsoleWindow().0 != 0 {
                     // Can't be None here due to new_any_response, so it's safe to use unchecked.
                     let result = prompt.prompt().unwrap_unchecked();
+
+                    // `unload` cancels the pending read, leaving an empty result behind.
+                    if IS_UNLOADING.load(Ordering::Relaxed) {
+                        break;
+                    }
+
+                    // A script is waiting on `dynamic::console_prompt`, the line is its answer.
+                    if let Some(sender) = CONSOLE_PROMPT_SENDER.lock().take() {
+                        let _ = sender.send(result.into_response());
+                        continue;
+                    }
+
                     on_console_command(
                         &result.prompt.data,
                         result.args,
                         Arc::clone(&base_core_clone),
                     );
                 }
+
+                // Scripts waiting for an answer get `None` once the sender is dropped.
+                IS_CONSOLE_LOOP_ACTIVE.store(false, Ordering::Relaxed);
+                CONSOLE_PROMPT_SENDER.lock().take();
             });
         }
     }
@@ -164,6 +236,7 @@ fn hook_based_on_renderer(base_core: Arc<RwLock<BaseCore>>, hmodule: isize) {
                 format!("{error:?}")
             )
         });
+        IS_UI_HOOKED.store(true, Ordering::Relaxed);
     } else {
         drop(builder);
     }
@@ -228,6 +301,48 @@ fn on_console_command(prompt: &str, args: Vec<String>, base_core: Arc<RwLock<Bas
                 base_core_reader.get_script_core().execute(std::mem::take(data), Arc::clone(&base_core), false, false);
             });
         }
+        "list_plugins" => {
+            let Some(base_core_reader) = base_core.try_read() else {
+                log!("[ERROR] Base Core is locked, try again later!");
+                return;
+            };
+
+            let plugins = base_core_reader
+                .get_arctic_core()
+                .get()
+                .map(|arctic| arctic.list_active_plugins())
+                .unwrap_or_default();
+
+            if plugins.is_empty() {
+                log!("[CMD] No plugins are injected.");
+                return;
+            }
+
+            log!("[CMD] Injected plugins:");
+            for plugin in plugins {
+                log!("» ", plugin);
+            }
+        }
+        "eject_plugin" => {
+            let Some(name) = args.get(1) else {
+                log!("[ERROR] No arguments passed! Usage: eject_plugin [name: String]");
+                return;
+            };
+
+            let Some(base_core_reader) = base_core.try_read() else {
+                log!("[ERROR] Base Core is locked, try again later!");
+                return;
+            };
+
+            let Some(arctic) = base_core_reader.get_arctic_core().get() else {
+                log!("[ERROR] No plugins are injected!");
+                return;
+            };
+
+            if arctic.eject_plugin(name) {
+                log!("[CMD] Ejecting plugin \"", name, "\"...");
+            }
+        }
         "exit" => std::process::exit(0),
         _ => (),
     }
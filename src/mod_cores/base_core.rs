@@ -1,14 +1,18 @@
-use crate::utils::{
-    api::API,
-    config::Config,
-    crosscom::{CrossCom, CrossComState},
-    eguiutils::ImGuiUtils,
-    extensions::OptionExt,
-    prompter::Prompter,
-    runedetour::RDetour,
-    scripting::{arctic::Arctic, script_core::ScriptCore},
-    stringutils::StringUtils,
-    ui::customwindows::CustomWindowsUtils,
+use crate::{
+    utils::{
+        api::API,
+        config::Config,
+        crosscom::{CrossCom, CrossComState},
+        eguiutils::{ImGuiUtils, ScriptUiQueues},
+        extensions::OptionExt,
+        generichooks::GenericHoooks,
+        prompter::Prompter,
+        runedetour::RDetour,
+        scripting::{arctic::Arctic, script_core::ScriptCore},
+        stringutils::StringUtils,
+        ui::customwindows::CustomWindowsUtils,
+    },
+    winutils::WinUtils,
 };
 use parking_lot::RwLock;
 use std::sync::{Arc, LazyLock, OnceLock};
@@ -32,6 +36,12 @@ pub struct BaseCore {
 
     /// ImGuiUtils instance.
     imgui_utils: Arc<RwLock<ImGuiUtils>>,
+
+    /// Work queued by scripts for the render thread, shared with `imgui_utils`.
+    script_ui_queues: &'static ScriptUiQueues,
+
+    /// Generic WinAPI hooks instance.
+    generic_hooks: &'static GenericHoooks,
 }
 
 thread_safe_structs!(BaseCore);
@@ -42,8 +52,12 @@ impl BaseCore {
         let config: &'static Config = Box::leak(Box::default());
         let use_local_server = config.get_use_local_server();
         let startup_channel = config.get_startup_channel();
+        let max_payload_size = config.get_max_payload_size();
+        let auto_reconnect = config.get_auto_reconnect();
+        let server_heartbeat = config.get_server_heartbeat();
         RDetour::register_all_detours();
 
+        let script_ui_queues: &'static ScriptUiQueues = Box::leak(Box::default());
         Self {
             config,
             crosscom: {
@@ -58,12 +72,21 @@ impl BaseCore {
                 });
 
                 // Validate version as soon as we are connected.
-                API::validate_version(Self::connect_crosscom(username, channel, use_local_server))
+                API::validate_version(Self::connect_crosscom(
+                    username,
+                    channel,
+                    use_local_server,
+                    max_payload_size,
+                    auto_reconnect,
+                    server_heartbeat,
+                ))
             },
             script_core: LazyLock::new(|| Box::leak(Box::new(ScriptCore::init()))),
             custom_window_utils: LazyLock::new(|| Box::leak(Box::default())),
             arctic_core: OnceLock::new(),
-            imgui_utils: Arc::new(RwLock::new(ImGuiUtils::new())),
+            imgui_utils: Arc::new(RwLock::new(ImGuiUtils::new(script_ui_queues))),
+            script_ui_queues,
+            generic_hooks: Box::leak(Box::new(GenericHoooks::init())),
         }
     }
 
@@ -72,15 +95,21 @@ impl BaseCore {
         username: &'static str,
         channel: String,
         use_local_server: bool,
+        max_payload_size: usize,
+        auto_reconnect: bool,
+        server_heartbeat: bool,
     ) -> Arc<RwLock<CrossCom>> {
         let instance = Arc::new(RwLock::new(CrossCom::init(
             username,
             channel.to_owned(),
             use_local_server,
+            max_payload_size,
+            auto_reconnect,
+            server_heartbeat,
         )));
         let instance_clone = Arc::clone(&instance);
 
-        std::thread::spawn(move || {
+        WinUtils::spawn_worker(move || {
             let reader = instance_clone.try_read().unwrap_or_crash(zencstr!(
                 "[ERROR] Failed reading CrossCom, cannot start connecting!"
             ));
@@ -111,6 +140,8 @@ impl BaseCore {
             // If it has been 10 seconds and we aren't connected, ask the user if they want to try
             // again or give up.
             if elapsed == 10.0 && !is_connected {
+                // Stops the retries of this instance, a new one is created when reconnecting.
+                reader.stop();
                 drop(reader);
                 drop(instance);
 
@@ -119,7 +150,13 @@ impl BaseCore {
                     log!("[PROMPT] Reconnecting...");
                     drop(prompt);
 
-                    break Self::connect_crosscom(username, channel, use_local_server);
+                    break Self::connect_crosscom(
+                        username,
+                        channel,
+                        use_local_server,
+                        max_payload_size,
+                        auto_reconnect,
+                    );
                 }
 
                 crash!("[ERROR] Failed connecting to the server, perhaps your serial is incorrect, or the server is down?");
@@ -140,7 +177,7 @@ impl BaseCore {
             self.get_crosscom(),
             move |script| {
                 let self_arc = Arc::clone(&self_arc);
-                script_core.execute(script, self_arc, false, false);
+                script_core.execute_remote(script, self_arc);
             },
         );
     }
@@ -174,4 +211,14 @@ impl BaseCore {
     pub fn get_imgui_utils(&self) -> Arc<RwLock<ImGuiUtils>> {
         Arc::clone(&self.imgui_utils)
     }
+
+    /// Gets the queues which scripts push toasts, prompts and fonts into.
+    pub const fn get_script_ui_queues(&self) -> &'static ScriptUiQueues {
+        self.script_ui_queues
+    }
+
+    /// Gets the `GenericHoooks` instance.
+    pub const fn get_generic_hooks(&self) -> &'static GenericHoooks {
+        self.generic_hooks
+    }
 }
@@ -4,13 +4,28 @@ use crate::{
         cryptutils::CryptUtils,
         eguiutils::{ContentFrameData, ImGuiUtils},
     },
+    winutils::WinUtils,
 };
+use flate2::Crc;
 use hudhook::imgui::{self};
 use parking_lot::RwLock;
-use std::{cell::RefCell, sync::Arc};
+use rkyv::AlignedVec;
+use std::{
+    cell::RefCell,
+    path::Path,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Name of the community content cache file, stored next to the DLL.
+const COMMUNITY_CACHE_FILE: &str = "community_cache.bin";
 
 /// Community-published item data.
 #[derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize, PartialEq, Eq, Debug, Clone)]
+#[archive(check_bytes)]
 pub struct CommunityItem {
     /// Item name.
     pub name: String,
@@ -27,11 +42,21 @@ pub struct CommunityWindow {
     /// Base Core which we use to access the script virtual machine.
     base_core: Arc<RwLock<BaseCore>>,
 
-    /// Community item data.
-    community_scripts: Vec<CommunityItem>,
+    /// Community item data, filled from the cache and/or the server.
+    community_scripts: Arc<RwLock<Vec<CommunityItem>>>,
 
     content_frame_data: RefCell<ContentFrameData>,
 
+    /// Set once fetched content replaces `community_scripts`, so that the frame layout starts
+    /// over with the new items.
+    is_content_refreshed: Arc<AtomicBool>,
+
+    /// Current search query, items are filtered by name and summary.
+    search_query: String,
+
+    /// Decrypted code of the item which should be copied into the code editor.
+    pending_editor_code: RefCell<Option<String>>,
+
     /// Center group size.
     group_size: [f32; 2],
 }
@@ -40,28 +65,128 @@ thread_safe_structs!(CommunityWindow);
 
 impl CommunityWindow {
     /// Initializes the window.
+    /// Cached content is displayed right away, and fetched from the server in the background if
+    /// there's no cache or if it's older than `community_cache_ttl`.
     pub fn init(base_core: Arc<RwLock<BaseCore>>) -> Self {
-        let base_core_clone = Arc::clone(&base_core);
+        let base_core_reader = base_core.read();
+        let config = base_core_reader.get_config();
+        let cache_path = Path::new(config.get_path()).join(COMMUNITY_CACHE_FILE);
+        let cache_ttl = config.get_community_cache_ttl();
+        let crosscom = base_core_reader.get_crosscom();
+        drop(base_core_reader);
+
+        let community_scripts = Arc::new(RwLock::new(Vec::new()));
+        let is_cache_fresh = match Self::load_cache(&cache_path) {
+            Some((cached_scripts, age)) => {
+                *community_scripts.write() = cached_scripts;
+                age < cache_ttl
+            }
+            None => false,
+        };
+
+        let is_content_refreshed = Arc::new(AtomicBool::new(false));
+        if !is_cache_fresh {
+            let community_scripts = Arc::clone(&community_scripts);
+            let is_content_refreshed = Arc::clone(&is_content_refreshed);
+
+            // Request content in a new thread, since we don't want to block the main thread.
+            WinUtils::spawn_worker(move || {
+                log!("[INFO] Requesting community content...");
+                let Some(content) = crosscom.read().get_community_content() else {
+                    log!("[ERROR] Failed requesting community content!");
+                    return;
+                };
+
+                log!("[INFO] Community content received!");
 
-        // Request content in a new task, since we don't want to block the main thread.
-        log!("[INFO] Requesting community content...");
-        let community_scripts = base_core_clone
-            .read()
-            .get_crosscom()
-            .read()
-            .get_community_content();
-        log!("[INFO] Community content received!");
+                Self::save_cache(&cache_path, &content);
+                *community_scripts.write() = content;
+                is_content_refreshed.store(true, Ordering::Relaxed);
+            });
+        }
 
         Self {
             base_core,
             community_scripts,
             content_frame_data: RefCell::default(),
+            is_content_refreshed,
+            search_query: String::new(),
+            pending_editor_code: RefCell::default(),
             group_size: [0.0, 0.0],
         }
     }
 
+    /// Loads the cached community content, alongside with its age in seconds.
+    /// The cache is laid out as the write timestamp, the content length, the CRC32 checksum of
+    /// the content and then the content.
+    /// Caches which fail validation are removed.
+    fn load_cache(path: &Path) -> Option<(Vec<CommunityItem>, u64)> {
+        let bytes = std::fs::read(path).ok()?;
+        let Some((content, timestamp)) = Self::read_cache(&bytes) else {
+            log!("[WARN] Community cache is corrupted, removing it!");
+            if let Err(error) = std::fs::remove_file(path) {
+                log!("[ERROR] Failed removing community cache, error: ", error);
+            }
+
+            return None;
+        };
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?.as_secs();
+        Some((content, now.saturating_sub(timestamp)))
+    }
+
+    /// Validates and deserializes the community cache, returning the content and when it was
+    /// written.
+    /// Returns `None` if the length, checksum or archived content don't check out.
+    fn read_cache(bytes: &[u8]) -> Option<(Vec<CommunityItem>, u64)> {
+        let (timestamp, bytes) = bytes.split_first_chunk::<8>()?;
+        let (len, bytes) = bytes.split_first_chunk::<8>()?;
+        let (checksum, bytes) = bytes.split_first_chunk::<4>()?;
+        if u64::from_le_bytes(*len) != bytes.len() as u64 {
+            return None;
+        }
+
+        let mut crc = Crc::new();
+        crc.update(bytes);
+        if crc.sum() != u32::from_le_bytes(*checksum) {
+            return None;
+        }
+
+        let mut aligned_bytes = AlignedVec::with_capacity(bytes.len());
+        aligned_bytes.extend_from_slice(bytes);
+        let content = rkyv::from_bytes::<Vec<CommunityItem>>(&aligned_bytes).ok()?;
+        Some((content, u64::from_le_bytes(*timestamp)))
+    }
+
+    /// Saves `content` to the community cache, alongside with the current timestamp.
+    #[allow(clippy::ptr_arg)]
+    fn save_cache(path: &Path, content: &Vec<CommunityItem>) {
+        let Ok(serialized) = rkyv::to_bytes::<_, 256>(content) else {
+            log!("[ERROR] Failed serializing community content for caching!");
+            return;
+        };
+
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_secs());
+
+        let mut crc = Crc::new();
+        crc.update(&serialized);
+
+        let mut bytes = Vec::with_capacity(serialized.len() + 20);
+        bytes.extend_from_slice(&timestamp.to_le_bytes());
+        bytes.extend_from_slice(&(serialized.len() as u64).to_le_bytes());
+        bytes.extend_from_slice(&crc.sum().to_le_bytes());
+        bytes.extend_from_slice(&serialized);
+
+        if let Err(error) = std::fs::write(path, bytes) {
+            log!("[ERROR] Failed writing community cache, error: ", error);
+        }
+    }
+
     /// Draws the window and its content.
-    pub fn draw(&mut self, ui: &imgui::Ui) {
+    /// Returns the code of an item if it should be copied into the code editor.
+    pub fn draw(&mut self, ui: &imgui::Ui) -> Option<String> {
         self.group_size = ImGuiUtils::draw_centered_widgets(
             ui,
             ui.window_size(),
@@ -78,8 +203,32 @@ impl CommunityWindow {
             },
         );
 
+        let search_width = ui.push_item_width(-1.0);
+        if ui
+            .input_text(zencstr!("##CommunitySearch"), &mut self.search_query)
+            .hint(zencstr!("Search..."))
+            .build()
+        {
+            // The frame layout is based on the index of each title, so start over with the
+            // filtered set.
+            self.content_frame_data.borrow_mut().titles.clear();
+        }
+        search_width.end();
+
+        let Some(community_scripts) = self.community_scripts.try_read() else {
+            return None;
+        };
+
+        if self.is_content_refreshed.swap(false, Ordering::Relaxed) {
+            self.content_frame_data.borrow_mut().titles.clear();
+        }
+
+        let search_query = self.search_query.to_lowercase();
         ui.columns(3, zencstr!("ViewColumn"), false);
-        for item_data in &*self.community_scripts {
+        for item_data in community_scripts
+            .iter()
+            .filter(|item_data| Self::matches_search(item_data, &search_query))
+        {
             ImGuiUtils::draw_content_frame(
                 ui,
                 &item_data.name,
@@ -89,6 +238,15 @@ impl CommunityWindow {
                 },
             );
         }
+
+        self.pending_editor_code.take()
+    }
+
+    /// Checks if the item's name or summary contains `search_query`, which has to be lowercase.
+    fn matches_search(item_data: &CommunityItem, search_query: &str) -> bool {
+        search_query.is_empty()
+            || item_data.name.to_lowercase().contains(search_query)
+            || item_data.summary.to_lowercase().contains(search_query)
     }
 
     /// Draws the inner content of a community item.
@@ -99,12 +257,15 @@ impl CommunityWindow {
                 return;
             };
 
-            base_core_reader.get_script_core().execute(
+            base_core_reader.get_script_core().execute_remote(
                 CryptUtils::decrypt(&item_data.code),
                 Arc::clone(&self.base_core),
-                false,
-                false,
             );
         }
+
+        ui.same_line();
+        if button!(ui, zencstr!("󰆏 Copy to Editor##", &item_data.name)) {
+            *self.pending_editor_code.borrow_mut() = Some(CryptUtils::decrypt(&item_data.code));
+        }
     }
 }
@@ -1,5 +1,8 @@
 use crate::{
-    globals::{CONTEXT_PTR, DELTA_TIME, IS_CURSOR_IN_UI},
+    globals::{
+        CONTEXT_PTR, DELTA_TIME, IS_CURSOR_IN_UI, IS_UNLOADING, MAX_TEXTURE_LOADS_PER_FRAME,
+        RENDER_THREAD_ID, SCROLL_DELTA,
+    },
     mod_cores::base_core::BaseCore,
     ui::community::CommunityWindow,
     utils::{
@@ -9,17 +12,35 @@ use crate::{
     },
     winutils::WinUtils,
 };
+use crossbeam_channel::{Receiver, RecvTimeoutError, Sender};
 use hudhook::{
-    imgui::{self, Condition, Context, Style, StyleColor, TreeNodeFlags},
+    imgui::{self, Condition, Context, Style, StyleColor, TextureId, TreeNodeFlags},
     ImguiRenderLoop, RenderContext,
 };
 use parking_lot::RwLock;
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     sync::{atomic::Ordering, Arc, OnceLock},
+    time::Duration,
 };
 use windows::Win32::Foundation::POINT;
 
+/// Image decoding job for the texture decoding thread, containing the image path and type.
+type DecodeJob = (String, CustomTextureType);
+
+/// Image decoded on the texture decoding thread, ready to be uploaded to the GPU.
+struct DecodedTexture {
+    /// Path to the image.
+    image_path: String,
+
+    /// Type of the image.
+    texture_type: CustomTextureType,
+
+    /// RGBA frames alongside their width and height, singular images only have 1 frame.
+    /// `None` if decoding failed.
+    frames: Option<Vec<(Vec<u8>, u32, u32)>>,
+}
+
 /// Simple basic ImGui windows, responsible for also drawing custom windows.
 pub struct DX11UI {
     /// `BaseCore` instance.
@@ -31,6 +52,9 @@ pub struct DX11UI {
     /// Script Name.
     script_name: String,
 
+    /// The last compile error from the code editor, if any.
+    compile_error: Option<String>,
+
     /// The community window.
     community_window: OnceLock<CommunityWindow>,
 
@@ -49,6 +73,22 @@ pub struct DX11UI {
     /// Invalid textures that should be removed the next frame.
     invalid_textures: Vec<String>,
 
+    /// Sends images to the texture decoding thread.
+    decode_job_sender: Sender<DecodeJob>,
+
+    /// Receives decoded images from the texture decoding thread.
+    decoded_texture_receiver: Receiver<DecodedTexture>,
+
+    /// Images currently being decoded, so that they aren't decoded twice.
+    decoding_textures: HashSet<String>,
+
+    /// GIF which didn't fit into the upload budget, alongside the number of frames uploaded so
+    /// far.
+    pending_upload: Option<(DecodedTexture, usize)>,
+
+    /// Released GPU textures, reused for the next uploads instead of allocating new ones.
+    free_texture_ids: Vec<TextureId>,
+
     /// Default dynamic ImGui style.
     default_style: Style,
 
@@ -73,30 +113,37 @@ impl DX11UI {
             .to_owned();
         drop(reader);
 
+        let (decode_job_sender, decoded_texture_receiver) = Self::spawn_texture_decoder();
         Self {
             base_core,
             code_editor_input: String::default(),
             script_name: String::with_capacity(24),
+            compile_error: None,
             community_window: OnceLock::new(),
             point: POINT::default(),
             display_ui: true,
             can_toggle_ui: true,
             crosscom_channel,
             invalid_textures: Vec::with_capacity(4),
+            decode_job_sender,
+            decoded_texture_receiver,
+            decoding_textures: HashSet::new(),
+            pending_upload: None,
+            free_texture_ids: Vec::new(),
             default_style: Style::default(),
             ui_colors_preset: String::default(),
         }
     }
 
     /// Executes Rune code.
-    fn execute_rune_code(&self) {
+    fn execute_rune_code(&mut self) {
         let Some(base_core_reader) = self.base_core.try_read() else {
             return;
         };
 
         // Execute the script.
         // CrossCom disabled for now when it comes to buttons, might be changed in the future.
-        base_core_reader.get_script_core().execute(
+        self.compile_error = base_core_reader.get_script_core().execute(
             self.code_editor_input.to_owned(),
             Arc::clone(&self.base_core),
             false,
@@ -151,23 +198,93 @@ impl DX11UI {
         self.can_toggle_ui = true;
     }
 
+    /// Spawns the thread which decodes images, so that decoding doesn't stall the render thread.
+    fn spawn_texture_decoder() -> (Sender<DecodeJob>, Receiver<DecodedTexture>) {
+        let (decode_job_sender, decode_job_receiver) = crossbeam_channel::unbounded::<DecodeJob>();
+        let (decoded_texture_sender, decoded_texture_receiver) = crossbeam_channel::unbounded();
+
+        WinUtils::spawn_worker(move || {
+            while !IS_UNLOADING.load(Ordering::Relaxed) {
+                // Timing out regularly, so that unloading isn't blocked by an idle decoder.
+                let (image_path, texture_type) =
+                    match decode_job_receiver.recv_timeout(Duration::from_millis(100)) {
+                        Ok(decode_job) => decode_job,
+                        Err(RecvTimeoutError::Timeout) => continue,
+                        Err(RecvTimeoutError::Disconnected) => break,
+                    };
+
+                let frames = Self::decode_texture(&image_path, texture_type);
+                let decoded_texture = DecodedTexture {
+                    image_path,
+                    texture_type,
+                    frames,
+                };
+
+                if decoded_texture_sender.send(decoded_texture).is_err() {
+                    break;
+                }
+            }
+        });
+
+        (decode_job_sender, decoded_texture_receiver)
+    }
+
+    /// Decodes the image at `image_path` into RGBA frames.
+    fn decode_texture(
+        image_path: &str,
+        texture_type: CustomTextureType,
+    ) -> Option<Vec<(Vec<u8>, u32, u32)>> {
+        if texture_type == CustomTextureType::Gif {
+            let frames = ImGuiUtils::extract_gif_frames(image_path)
+                .into_iter()
+                .map(|frame| {
+                    (
+                        frame.buffer.into_owned(),
+                        frame.width as u32,
+                        frame.height as u32,
+                    )
+                })
+                .collect();
+            return Some(frames);
+        }
+
+        let image = image::open(image_path);
+        let Ok(image) = image else {
+            log!(
+                "[ERROR] Failed loading image at path \"",
+                image_path,
+                "\" into memory, error: ",
+                image.unwrap_err()
+            );
+            return None;
+        };
+
+        let (width, height) = (image.width(), image.height());
+        Some(vec![(image.into_rgba8().into_raw(), width, height)])
+    }
+
     /// Caches uninitialized textures for custom windows.
+    /// Images are decoded on the texture decoding thread, only the GPU upload happens here.
     fn load_unitialized_textures(&mut self, render_context: &mut dyn RenderContext) {
-        let Some(cached_images) = self
+        let Some(custom_window_utils) = self
             .base_core
             .try_read()
-            .map(|reader| reader.get_custom_window_utils().get_cached_images())
+            .map(|reader| reader.get_custom_window_utils())
         else {
             log!("[ERROR] Failed reading BaseCore as it's currently busy, cannot load uninitialized textures!");
             return;
         };
 
+        self.release_freed_textures(custom_window_utils.take_freed_texture_ids(), render_context);
+        let cached_images = custom_window_utils.get_cached_images();
+
         let Ok(mut cached_textures) = cached_images.try_borrow_mut() else {
             log!("[ERROR] Cached textures is in use, cannot load uninitialized textures!");
             return;
         };
 
         if cached_textures.is_empty() {
+            self.pending_upload = None;
             if !self.invalid_textures.is_empty() {
                 self.invalid_textures.clear();
             }
@@ -175,66 +292,113 @@ impl DX11UI {
             return;
         }
 
+        let max_texture_loads = MAX_TEXTURE_LOADS_PER_FRAME.load(Ordering::Relaxed);
+        let mut texture_loads = 0;
+
+        // Upload the images which have finished decoding, capped to spread the uploads across
+        // frames.
+        while texture_loads < max_texture_loads {
+            let (decoded_texture, uploaded_frames) = match self.pending_upload.take() {
+                Some(pending_upload) => pending_upload,
+                None => {
+                    let Ok(decoded_texture) = self.decoded_texture_receiver.try_recv() else {
+                        break;
+                    };
+
+                    self.decoding_textures.remove(&decoded_texture.image_path);
+                    (decoded_texture, 0)
+                }
+            };
+
+            self.upload_decoded_texture(
+                decoded_texture,
+                uploaded_frames,
+                &mut texture_loads,
+                max_texture_loads,
+                &mut cached_textures,
+                render_context,
+            );
+        }
+
         // Only get the uninitialized textures.
         let uninitialized_textures = cached_textures.iter_mut().filter(|entry| {
             entry.1.texture_type != CustomTextureType::GifFrame && entry.1.texture_id.is_none()
         });
 
-        let mut gif_image_path = None;
-
         for (image_path, custom_texture) in uninitialized_textures {
-            let image_path = image_path.to_owned();
             let texture_type = custom_texture.texture_type;
 
             match texture_type {
-                CustomTextureType::Gif => {
-                    log!("[Texture Loader] Attempting to load GIF texture...");
+                CustomTextureType::Singular | CustomTextureType::Gif => {
+                    if self.decoding_textures.contains(image_path) {
+                        continue;
+                    }
+
+                    if texture_type == CustomTextureType::Gif {
+                        log!("[Texture Loader] Attempting to load GIF texture...");
+                    }
+
+                    if self
+                        .decode_job_sender
+                        .send((image_path.to_owned(), texture_type))
+                        .is_err()
+                    {
+                        log!(
+                            "[ERROR] Texture decoding thread has stopped, cannot load \"",
+                            image_path,
+                            "\"!"
+                        );
+                        self.invalid_textures.push(image_path.to_owned());
+                        continue;
+                    }
 
-                    // Save path for after this for-loop, as otherwise we risk deadlocks due to
-                    // held-on resources.
-                    gif_image_path = Some(image_path);
+                    self.decoding_textures.insert(image_path.to_owned());
                 }
                 CustomTextureType::GifFrame => {
                     crash!("[ERROR] Attempted processing CustomTextureType::GifFrame, escaping the filter!");
                 }
-                CustomTextureType::Singular => {
-                    let image = image::open(&image_path);
-                    let Ok(image) = image else {
+                CustomTextureType::Memory => {
+                    // Already decoded by `CustomWindowsUtils::cache_image_from_bytes`.
+                    if texture_loads >= max_texture_loads {
+                        continue;
+                    }
+
+                    texture_loads += 1;
+                    let Some(image) = custom_window_utils.take_pending_memory_image(image_path)
+                    else {
                         log!(
-                            "[ERROR] Failed loading image at path \"",
+                            "[ERROR] No decoded image found for in-memory path \"",
                             image_path,
-                            "\" into memory, error: ",
-                            image.unwrap_err()
+                            "\"!"
                         );
                         self.invalid_textures.push(image_path.to_owned());
-                        break;
+                        continue;
                     };
 
-                    let loaded_texture_id = render_context.load_texture(
-                        &image.to_rgba8().into_raw(),
+                    let loaded_texture_id = Self::upload_texture(
+                        &mut self.free_texture_ids,
+                        render_context,
+                        &image,
                         image.width(),
                         image.height(),
                     );
                     let Ok(loaded_texture_id) = loaded_texture_id else {
                         log!(
-                            "[ERROR] Failed loading image at path \"",
+                            "[ERROR] Failed loading in-memory image \"",
                             image_path,
                             "\" into the GPU, error: ",
                             loaded_texture_id.unwrap_err()
                         );
                         self.invalid_textures.push(image_path.to_owned());
-                        break;
+                        continue;
                     };
 
                     custom_texture.texture_id = Some(loaded_texture_id);
+                    custom_texture.size = [image.width(), image.height()];
                 }
             }
         }
 
-        if let Some(image_path) = gif_image_path.take() {
-            self.load_gif_frames(image_path, &mut cached_textures, render_context);
-        }
-
         for invalid_texture in &self.invalid_textures {
             cached_textures.remove(invalid_texture);
         }
@@ -242,20 +406,96 @@ impl DX11UI {
         self.invalid_textures.clear();
     }
 
-    /// Loads the frame of a GIF into the GPU and stores them as `image_path.frame_[frame_number]`.
-    fn load_gif_frames(
+    /// Shrinks the textures of images removed from the cache down to a single pixel, releasing
+    /// their GPU memory, and keeps their TextureIds around for the next uploads.
+    fn release_freed_textures(
         &mut self,
-        image_path: String,
+        freed_texture_ids: Vec<TextureId>,
+        render_context: &mut dyn RenderContext,
+    ) {
+        for texture_id in freed_texture_ids {
+            if let Err(error) = render_context.replace_texture(texture_id, &[0; 4], 1, 1) {
+                log!("[ERROR] Failed releasing GPU texture, error: ", error);
+                continue;
+            }
+
+            self.free_texture_ids.push(texture_id);
+        }
+    }
+
+    /// Uploads RGBA `data` into the GPU, reusing a released texture if there is one.
+    fn upload_texture(
+        free_texture_ids: &mut Vec<TextureId>,
+        render_context: &mut dyn RenderContext,
+        data: &[u8],
+        width: u32,
+        height: u32,
+    ) -> Result<TextureId, impl std::fmt::Display> {
+        let Some(texture_id) = free_texture_ids.pop() else {
+            return render_context.load_texture(data, width, height);
+        };
+
+        let result = render_context.replace_texture(texture_id, data, width, height);
+        if result.is_err() {
+            // Keep the texture for a later upload.
+            free_texture_ids.push(texture_id);
+        }
+
+        result.map(|_| texture_id)
+    }
+
+    /// Uploads an image decoded on the texture decoding thread into the GPU, starting at frame
+    /// `uploaded_frames`.
+    /// GIF frames are stored as `image_path.frame_[frame_number]`.
+    /// Every frame counts against `max_texture_loads`, GIFs going over the budget are continued
+    /// from `self.pending_upload` on the next frame.
+    fn upload_decoded_texture(
+        &mut self,
+        decoded_texture: DecodedTexture,
+        uploaded_frames: usize,
+        texture_loads: &mut usize,
+        max_texture_loads: usize,
         cached_images: &mut HashMap<String, CustomTexture>,
         render_context: &mut dyn RenderContext,
     ) {
-        let extracted_frames = ImGuiUtils::extract_gif_frames(&image_path);
-        for (i, frame) in extracted_frames.iter().enumerate() {
+        let image_path = &decoded_texture.image_path;
+        let texture_type = decoded_texture.texture_type;
+
+        // The image may have been unloaded while it was being decoded or uploaded.
+        if !cached_images
+            .get(image_path)
+            .is_some_and(|cached_image| cached_image.texture_id.is_some() == (uploaded_frames > 0))
+        {
+            return;
+        }
+
+        let Some(frames) = decoded_texture
+            .frames
+            .as_ref()
+            .filter(|frames| !frames.is_empty())
+        else {
+            self.invalid_textures.push(image_path.to_owned());
+            return;
+        };
+
+        let mut next_frame = None;
+        for (i, (buffer, width, height)) in frames.iter().enumerate().skip(uploaded_frames) {
+            if *texture_loads >= max_texture_loads {
+                next_frame = Some(i);
+                break;
+            }
+
+            *texture_loads += 1;
             let i = i + 1; // Make the index be more human-readable and not count from 0 when
                            // displaying.
 
-            let loaded_texture_id =
-                render_context.load_texture(&frame.buffer, frame.width as u32, frame.height as u32);
+            let loaded_texture_id = Self::upload_texture(
+                &mut self.free_texture_ids,
+                render_context,
+                buffer,
+                *width,
+                *height,
+            );
             let Ok(loaded_texture_id) = loaded_texture_id else {
                 log!(
                     "[ERROR] Failed uploading image at path \"",
@@ -270,18 +510,23 @@ impl DX11UI {
             };
 
             if i == 1 {
-                // Not an elegant solution, the ideal way would be to remove the image_path entry,
-                // in order to not keep 2 entries with the same resource (image_path & first
-                // frame).
+                // Not an elegant solution for GIFs, the ideal way would be to remove the
+                // image_path entry, in order to not keep 2 entries with the same resource
+                // (image_path & first frame).
                 cached_images.insert(
                     image_path.to_owned(),
                     CustomTexture {
                         texture_id: Some(loaded_texture_id),
-                        texture_type: CustomTextureType::Gif,
+                        texture_type,
+                        size: [*width, *height],
                     },
                 );
             }
 
+            if texture_type != CustomTextureType::Gif {
+                return;
+            }
+
             let frame_path = ozencstr!(image_path, ".frame_", i);
             log!(
                 "[Texture Loader] Loaded frame ",
@@ -294,15 +539,21 @@ impl DX11UI {
                 CustomTexture {
                     texture_id: Some(loaded_texture_id),
                     texture_type: CustomTextureType::GifFrame,
+                    size: [*width, *height],
                 },
             );
         }
 
+        if let Some(next_frame) = next_frame {
+            self.pending_upload = Some((decoded_texture, next_frame));
+            return;
+        }
+
         log!(
             "[Texture Loader] GIF frames loaded from \"",
             image_path,
             "\", frames: ",
-            extracted_frames.len(),
+            frames.len(),
             "!"
         );
     }
@@ -363,11 +614,19 @@ impl ImguiRenderLoop for DX11UI {
     ) {
         CONTEXT_PTR.store(std::ptr::addr_of!(_ctx) as i64, Ordering::Relaxed);
         self.load_unitialized_textures(_render_context);
+
+        if let Some(base_core_reader) = self.base_core.try_read()
+            && let Some(imgui_utils_reader) = base_core_reader.get_imgui_utils().try_read()
+        {
+            imgui_utils_reader.load_pending_fonts(_ctx, base_core_reader.get_config());
+        }
     }
 
     /// Renders the UI.
     fn render(&mut self, ui: &mut imgui::Ui, _render_context: &mut dyn RenderContext) {
+        RENDER_THREAD_ID.get_or_init(|| std::thread::current().id());
         DELTA_TIME.store(ui.io().delta_time, Ordering::Relaxed);
+        SCROLL_DELTA.store(ui.io().mouse_wheel, Ordering::Relaxed);
 
         let base_core = Arc::clone(&self.base_core);
         let Some(base_core_reader) = base_core.try_read() else {
@@ -382,16 +641,18 @@ impl ImguiRenderLoop for DX11UI {
         };
 
         imgui_utils_reader.draw_screen_messages(ui);
+        imgui_utils_reader.draw_toasts(ui);
+        imgui_utils_reader.draw_prompts(ui);
         drop(imgui_utils_reader);
 
         self.on_toggle_ui();
+        script_core.poll_hotkeys(Arc::clone(&self.base_core));
         script_core.call_frame_update_callbacks(None, None);
         if !self.display_ui {
             return;
         }
 
         IS_CURSOR_IN_UI.store(ui.io().want_capture_mouse, Ordering::Relaxed);
-        self.load_unitialized_textures(_render_context);
         ImGuiUtils::sync_clipboard(ui);
 
         base_core_reader
@@ -413,6 +674,10 @@ impl ImguiRenderLoop for DX11UI {
                     self.execute_rune_code();
                 }
 
+                if let Some(compile_error) = &self.compile_error {
+                    ui.text_colored([1.0, 0.3, 0.3, 1.0], compile_error);
+                }
+
                 self.draw_script_management(ui);
                 ImGuiUtils::render_software_cursor(ui, &mut self.point);
             });
@@ -422,7 +687,10 @@ impl ImguiRenderLoop for DX11UI {
             .collapsed(true, Condition::Once)
             .build(|| {
                 if let Some(community_window) = self.community_window.get_mut() {
-                    community_window.draw(ui);
+                    if let Some(code) = community_window.draw(ui) {
+                        self.code_editor_input = code;
+                    }
+
                     ImGuiUtils::render_software_cursor(ui, &mut self.point);
                 } else {
                     self.community_window
@@ -7,8 +7,9 @@ pub struct CompressionUtils;
 
 impl CompressionUtils {
     /// Compresses the specified bytes.
-    pub fn write_compressed(mut bytes: Vec<u8>) -> Vec<u8> {
-        let mut encoder = ZlibEncoder::new(Vec::with_capacity(512), Compression::fast());
+    /// `level` ranges from `0` (no compression) to `9` (best compression).
+    pub fn write_compressed(mut bytes: Vec<u8>, level: u32) -> Vec<u8> {
+        let mut encoder = ZlibEncoder::new(Vec::with_capacity(512), Compression::new(level));
         encoder
             .write_all(&bytes)
             .dynamic_expect(zencstr!("Failed writing bytes"));
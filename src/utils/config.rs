@@ -1,15 +1,26 @@
-use super::{extensions::ResultExtensions, runedetour::COLLECT_PARAMS_COUNT};
+use super::{
+    extensions::ResultExtensions,
+    runedetour::{COLLECT_PARAMS_COUNT, DETOUR_POOL_SIZE, MAX_DETOURS},
+};
 use crate::{
-    globals::CONTEXT_PTR,
+    globals::{
+        COMPRESSION_LEVEL, CONTEXT_PTR, GIF_FPS, LOG_FILE, LOG_TIMESTAMPS,
+        MAX_TEXTURE_LOADS_PER_FRAME, SIDE_MESSAGE_COLOR, SIDE_MESSAGE_MAX_LINES, SIDE_MESSAGE_POS,
+        SOFTWARE_CURSOR_COLOR, SOFTWARE_CURSOR_SIZE,
+    },
     utils::extensions::OptionExt,
     winutils::{Renderer, WinUtils},
 };
+use dashmap::DashMap;
+use parking_lot::Mutex;
 use serde_jsonc::Value;
 use std::{
     fmt::Display,
-    fs::read_to_string,
-    io::{Result, Write},
+    fs::{read_to_string, OpenOptions},
+    io::{BufWriter, Result, Write},
+    path::Path,
     sync::{atomic::Ordering, Arc, OnceLock},
+    time::SystemTime,
 };
 
 /// Simple JSON config.
@@ -17,6 +28,10 @@ pub struct Config {
     /// Cached config.
     cached_config: OnceLock<Value>,
 
+    /// Script settings, loaded from and saved into script_settings.json.
+    /// Kept apart from config.jsonc so that scripts can't change dynamic's own settings.
+    script_settings: DashMap<String, Value>,
+
     /// Directory path.
     path: &'static str,
 
@@ -83,8 +98,107 @@ impl Default for Config {
             }
         }
 
+        if let Some(detour_pool_size) =
+            cached_config_ref[&zencstr!("detour_pool_size").data].as_u64()
+        {
+            let detour_pool_size = (detour_pool_size as usize).min(MAX_DETOURS);
+            DETOUR_POOL_SIZE.store(detour_pool_size, Ordering::Relaxed);
+            log!(
+                "[Config]: RDetour pool size set to ",
+                detour_pool_size,
+                " (max ",
+                MAX_DETOURS,
+                ")!"
+            );
+        }
+
+        if let Some(cursor_size) = cached_config_ref[&zencstr!("cursor_size").data].as_f64() {
+            SOFTWARE_CURSOR_SIZE.store(cursor_size as f32, Ordering::Relaxed);
+        }
+
+        if let Some(cursor_color) = cached_config_ref[&zencstr!("cursor_color").data].as_array() {
+            if let Some(cursor_color) = Self::array_to_f32s::<4>(cursor_color) {
+                SOFTWARE_CURSOR_COLOR.get_or_init(|| cursor_color);
+            } else {
+                log!("[WARN] config.jsonc -> cursor_color must contain 4 numbers (RGBA), using white!");
+            }
+        }
+
+        if let Some(side_message_pos) =
+            cached_config_ref[&zencstr!("side_message_pos").data].as_array()
+        {
+            if let Some(side_message_pos) = Self::array_to_f32s::<2>(side_message_pos) {
+                SIDE_MESSAGE_POS.get_or_init(|| side_message_pos);
+            } else {
+                log!("[WARN] config.jsonc -> side_message_pos must contain 2 numbers (X, Y), using [0.0, 100.0]!");
+            }
+        }
+
+        if let Some(side_message_color) =
+            cached_config_ref[&zencstr!("side_message_color").data].as_array()
+        {
+            if let Some(side_message_color) = Self::array_to_f32s::<4>(side_message_color) {
+                SIDE_MESSAGE_COLOR.get_or_init(|| side_message_color);
+            } else {
+                log!("[WARN] config.jsonc -> side_message_color must contain 4 numbers (RGBA), using white!");
+            }
+        }
+
+        if let Some(side_message_max_lines) =
+            cached_config_ref[&zencstr!("side_message_max_lines").data].as_u64()
+        {
+            // At least 1 line, as 0 would clear the side messages on every message.
+            SIDE_MESSAGE_MAX_LINES
+                .store((side_message_max_lines as usize).max(1), Ordering::Relaxed);
+        }
+
+        if let Some(gif_fps) = cached_config_ref[&zencstr!("gif_fps").data].as_f64() {
+            GIF_FPS.store(gif_fps as f32, Ordering::Relaxed);
+        }
+
+        if let Some(max_texture_loads_per_frame) =
+            cached_config_ref[&zencstr!("max_texture_loads_per_frame").data].as_u64()
+        {
+            // At least 1, otherwise textures would never load.
+            MAX_TEXTURE_LOADS_PER_FRAME.store(
+                (max_texture_loads_per_frame as usize).max(1),
+                Ordering::Relaxed,
+            );
+        }
+
+        if let Some(compression_level) =
+            cached_config_ref[&zencstr!("compression_level").data].as_u64()
+        {
+            COMPRESSION_LEVEL.store(compression_level.min(9) as u32, Ordering::Relaxed);
+        }
+
+        if let Some(log_timestamps) = cached_config_ref[&zencstr!("log_timestamps").data].as_bool()
+        {
+            LOG_TIMESTAMPS.store(log_timestamps, Ordering::Relaxed);
+        }
+
+        if let Some(log_file) = cached_config_ref[&zencstr!("log_file").data].as_str() {
+            match OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(Path::new(&dir_path).join(log_file))
+            {
+                Ok(file) => {
+                    LOG_FILE.get_or_init(|| Mutex::new(BufWriter::new(file)));
+                }
+                Err(error) => {
+                    log!(
+                        "[ERROR] Couldn't open config.jsonc -> log_file, error: ",
+                        error
+                    );
+                }
+            }
+        }
+
+        let script_settings = Self::load_script_settings(&dir_path);
         Self {
             cached_config,
+            script_settings,
             path: dir_path.leak(),
             serials: Arc::new(cfg_serials),
         }
@@ -92,6 +206,39 @@ impl Default for Config {
 }
 
 impl Config {
+    /// Converts a JSON array into `N` floats.
+    /// Returns `None` if the array doesn't contain exactly `N` numbers.
+    fn array_to_f32s<const N: usize>(array: &[Value]) -> Option<[f32; N]> {
+        let floats = array
+            .iter()
+            .filter_map(|value| value.as_f64().map(|value| value as f32))
+            .collect::<Vec<f32>>();
+
+        <[f32; N]>::try_from(floats).ok()
+    }
+
+    /// Loads the script settings saved through `save` from script_settings.json inside of
+    /// `dir_path`.
+    fn load_script_settings(dir_path: &str) -> DashMap<String, Value> {
+        let Ok(content) =
+            read_to_string(Path::new(dir_path).join(&zencstr!("script_settings.json").data))
+        else {
+            // Nothing has been saved yet.
+            return DashMap::new();
+        };
+
+        match serde_jsonc::from_str::<serde_jsonc::Map<String, Value>>(&content) {
+            Ok(script_settings) => script_settings.into_iter().collect(),
+            Err(error) => {
+                log!(
+                    "[ERROR] Failed parsing script_settings.json, ignoring it! Error: ",
+                    error
+                );
+                DashMap::new()
+            }
+        }
+    }
+
     /// Returns a reference to the config.
     fn get(&self) -> &Value {
         self.cached_config.get().unwrap_or_crash(zencstr!(
@@ -99,6 +246,64 @@ impl Config {
         ))
     }
 
+    /// Gets the value of `key`, preferring script settings over the values present in
+    /// config.jsonc.
+    fn get_value(&self, key: &str) -> Option<Value> {
+        if let Some(value) = self.script_settings.get(key) {
+            return Some(value.to_owned());
+        }
+
+        self.get().get(key).cloned()
+    }
+
+    /// Sets the script setting `key` in memory. Call `save` to write it into
+    /// script_settings.json.
+    /// Script settings never change dynamic's own settings, only the values scripts read back.
+    pub fn set_value(&self, key: String, value: Value) {
+        self.script_settings.insert(key, value);
+    }
+
+    /// Saves all script settings into script_settings.json.
+    /// config.jsonc is never written to, so its comments and dynamic's own settings are left
+    /// untouched.
+    pub fn save(&self) -> bool {
+        let script_settings = self
+            .script_settings
+            .iter()
+            .map(|entry| (entry.key().to_owned(), entry.value().to_owned()))
+            .collect::<serde_jsonc::Map<String, Value>>();
+
+        let content = match serde_jsonc::to_string_pretty(&script_settings) {
+            Ok(content) => content,
+            Err(error) => {
+                log!("[ERROR] Failed serializing config, error: ", error);
+                return false;
+            }
+        };
+
+        self.save_to_file(&zencstr!("script_settings.json").data, &content)
+    }
+
+    /// Gets a user-defined string value from the config by its key.
+    pub fn get_string(&self, key: &str) -> Option<String> {
+        self.get_value(key)?.as_str().map(ToOwned::to_owned)
+    }
+
+    /// Gets a user-defined integer value from the config by its key.
+    pub fn get_i64(&self, key: &str) -> Option<i64> {
+        self.get_value(key)?.as_i64()
+    }
+
+    /// Gets a user-defined boolean value from the config by its key.
+    pub fn get_bool(&self, key: &str) -> Option<bool> {
+        self.get_value(key)?.as_bool()
+    }
+
+    /// Gets a user-defined float value from the config by its key.
+    pub fn get_f64(&self, key: &str) -> Option<f64> {
+        self.get_value(key)?.as_f64()
+    }
+
     /// Should the console be freed?
     pub fn get_free_console(&self) -> bool {
         self.get()[&zencstr!("free_console").data]
@@ -106,6 +311,13 @@ impl Config {
             .unwrap_or_default()
     }
 
+    /// Are scripts allowed to send outbound HTTP requests?
+    pub fn get_allow_http(&self) -> bool {
+        self.get()[&zencstr!("allow_http").data]
+            .as_bool()
+            .unwrap_or_default()
+    }
+
     /// Should 0.0.0.0 be used over the public server ip?
     pub fn get_use_local_server(&self) -> bool {
         self.get()[&zencstr!("use_local_server").data]
@@ -113,6 +325,35 @@ impl Config {
             .unwrap_or_default()
     }
 
+    /// How long in seconds the cached community content is used before it's fetched again.
+    pub fn get_community_cache_ttl(&self) -> u64 {
+        self.get()[&zencstr!("community_cache_ttl").data]
+            .as_u64()
+            .unwrap_or(3600)
+    }
+
+    /// Should CrossCom try to reconnect after being disconnected?
+    pub fn get_auto_reconnect(&self) -> bool {
+        self.get()[&zencstr!("auto_reconnect").data]
+            .as_bool()
+            .unwrap_or(true)
+    }
+
+    /// Should CrossCom ping the server to detect dead connections?
+    /// Only enable for servers which answer `Ping`, others respond with an error.
+    pub fn get_server_heartbeat(&self) -> bool {
+        self.get()[&zencstr!("server_heartbeat").data]
+            .as_bool()
+            .unwrap_or(false)
+    }
+
+    /// Max size in bytes of scripts and messages sent through CrossCom.
+    pub fn get_max_payload_size(&self) -> usize {
+        self.get()[&zencstr!("max_payload_size").data]
+            .as_u64()
+            .map_or(1024 * 1024, |max_payload_size| max_payload_size as usize)
+    }
+
     /// Takes `name` and appends it to the back of `self.path`, returning the full path.
     fn get_full_path_for(&self, name: &str) -> Option<String> {
         if name.is_empty() {
@@ -174,6 +415,13 @@ impl Config {
         false
     }
 
+    /// Gets the last time the relative file was modified.
+    pub fn get_file_modified_time(&self, name: &str) -> Option<SystemTime> {
+        std::fs::metadata(self.get_full_path_for(name)?)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+    }
+
     /// Attempts to read the relative file and return the content as bytes.
     pub fn get_file_content_bytes<S: AsRef<str> + Display>(&self, name: S) -> Result<Vec<u8>> {
         std::fs::read(
@@ -356,6 +604,14 @@ impl Config {
             .unwrap_or(true)
     }
 
+    /// If `Some()`, the amount of instructions a script may execute before it's stopped.
+    /// If `None`, scripts may run for as long as they want.
+    pub fn get_script_instruction_budget(&self) -> Option<usize> {
+        self.get()[&zencstr!("script_instruction_budget").data]
+            .as_u64()
+            .map(|budget| budget as usize)
+    }
+
     /// If `Some()`, the `startup_channel` string is returned from the config.
     /// If not, or the value wasn't a string - it returns `None`.
     pub fn get_startup_channel(&self) -> Option<String> {
@@ -1,4 +1,5 @@
 use crate::{
+    globals::{COMPRESSION_LEVEL, IS_UNLOADING},
     ui::community::CommunityItem,
     utils::{
         compressionutils::CompressionUtils, extensions::OptionExt,
@@ -9,17 +10,31 @@ use message_io::{
     network::{Endpoint, NetEvent, Transport},
     node::{self, NodeEvent, NodeHandler},
 };
+use parking_lot::RwLock;
 use std::{
     cell::{Cell, OnceCell, RefCell},
     collections::HashMap,
     sync::{
-        atomic::{AtomicBool, Ordering},
-        Arc, OnceLock,
+        atomic::{AtomicBool, AtomicI64, Ordering},
+        Arc,
     },
+    time::{Duration, Instant},
 };
 
 use super::extensions::ResultExtensions;
 
+/// Max amount of reconnection attempts before giving up.
+const MAX_RECONNECT_ATTEMPTS: u32 = 5;
+
+/// How often a ping is sent to the server.
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How long to wait for a pong before the connection is considered dead.
+const PING_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// How long `send_and_wait` waits for the server to respond.
+const RESPONSE_TIMEOUT: Duration = Duration::from_secs(30);
+
 /// CrossCom outgoing client data.
 #[derive(rkyv::Archive, rkyv::Serialize, Default)]
 pub struct CrossComClientData {
@@ -99,6 +114,17 @@ pub enum DataType {
 
     /// Sent by the server and contains the font bytes.
     SendFonts(Vec<u8>, Vec<u8>),
+
+    /// Sends a plain string message from the client, to the server which then sends it to all
+    /// party members.
+    /// Unlike `SendScripts`, the content is never executed.
+    BroadcastMessage(String),
+
+    /// Sent periodically by the client to check if the connection is still alive.
+    Ping,
+
+    /// Sent by the server as a response to `Ping`.
+    Pong,
 }
 
 impl CrossComClientData {
@@ -114,6 +140,7 @@ impl CrossComClientData {
                     )
                 })
                 .to_vec(),
+            COMPRESSION_LEVEL.load(Ordering::Relaxed),
         )
     }
 }
@@ -126,6 +153,15 @@ pub struct CrossCom {
     /// Use the local server?
     use_local_server: bool,
 
+    /// Max size in bytes of outgoing scripts and messages.
+    max_payload_size: usize,
+
+    /// Try to reconnect when disconnected, rather than closing dynamic right away?
+    auto_reconnect: bool,
+
+    /// Ping the server to detect dead connections? Only supported by some servers.
+    server_heartbeat: bool,
+
     /// Current CrossCom state.
     state: Cell<CrossComState>,
 
@@ -135,11 +171,11 @@ pub struct CrossCom {
     /// Has the user requested to change channel?
     has_pending_channel_update: AtomicBool,
 
-    /// Server endpoint.
-    server_endpoint: OnceLock<Endpoint>,
+    /// Server endpoint, `None` while not connected.
+    server_endpoint: RwLock<Option<Endpoint>>,
 
-    /// Handler needed to send data to the server.
-    handler: OnceLock<NodeHandler<Signal>>,
+    /// Handler needed to send data to the server, `None` while not connected.
+    handler: RwLock<Option<NodeHandler<Signal>>>,
 
     /// Network Listener instance, needed for sending server messages across the rest of the
     /// client, and for receiving them on other ends.
@@ -147,6 +183,16 @@ pub struct CrossCom {
 
     /// Decompressed data vector.
     decompressed_data: RefCell<Vec<u8>>,
+
+    /// When the last unanswered ping was sent, `None` if there is none.
+    ping_sent_at: Cell<Option<Instant>>,
+
+    /// Round-trip time of the last answered ping in milliseconds, `-1` if no ping has been
+    /// answered yet.
+    ping_rtt: AtomicI64,
+
+    /// Has `stop` been called? If `true`, no more connection attempts are made.
+    is_stopped: AtomicBool,
 }
 
 thread_safe_structs!(CrossCom);
@@ -155,31 +201,106 @@ thread_safe_structs!(CrossCom);
 enum Signal {
     /// Signal used when connecting.
     ConnectSignal,
+
+    /// Timer signal used for sending pings and detecting dead connections.
+    Heartbeat,
 }
 
 impl CrossCom {
     /// Initializes `CrossCom`.
-    pub fn init(username: &'static str, mut channel: String, use_local_server: bool) -> Self {
+    pub fn init(
+        username: &'static str,
+        mut channel: String,
+        use_local_server: bool,
+        max_payload_size: usize,
+        auto_reconnect: bool,
+        server_heartbeat: bool,
+    ) -> Self {
         channel.truncate(64);
 
         Self {
             username,
             use_local_server,
+            max_payload_size,
+            auto_reconnect,
+            server_heartbeat,
             state: Cell::new(CrossComState::Disconnected),
             current_channel: RefCell::new(channel),
             has_pending_channel_update: AtomicBool::default(),
-            server_endpoint: OnceLock::new(),
-            handler: OnceLock::new(),
+            server_endpoint: RwLock::new(None),
+            handler: RwLock::new(None),
             network_listener: NetworkListener::new(),
             decompressed_data: RefCell::new(Vec::with_capacity(512)),
+            ping_sent_at: Cell::new(None),
+            ping_rtt: AtomicI64::new(-1),
+            is_stopped: AtomicBool::new(false),
         }
     }
 
     /// Attempts to connect to the server.
+    /// If `auto_reconnect` is enabled, disconnects are followed by up to `MAX_RECONNECT_ATTEMPTS`
+    /// reconnection attempts with an increasing delay, before dynamic is closed.
+    /// Returns once `stop` has been called.
     pub fn connect(&self) {
+        let mut attempts = 0;
+        loop {
+            if self.connect_once() {
+                attempts = 0;
+            }
+
+            if self.is_stopped.load(Ordering::Relaxed) {
+                return;
+            }
+
+            if !self.auto_reconnect || attempts == MAX_RECONNECT_ATTEMPTS {
+                crash!("[SERVER] Disconnected from server, closing dynamic!");
+            }
+
+            attempts += 1;
+            let delay = 1 << attempts;
+            log!(
+                "[SERVER] Reconnecting in ",
+                delay,
+                " seconds, attempt ",
+                attempts,
+                "/",
+                MAX_RECONNECT_ATTEMPTS,
+                "..."
+            );
+
+            // Sleeping in steps, so that `stop` doesn't have to wait for the whole delay.
+            for _ in 0..delay * 10 {
+                if self.is_stopped.load(Ordering::Relaxed) {
+                    return;
+                }
+
+                std::thread::sleep(Duration::from_millis(100));
+            }
+        }
+    }
+
+    /// Disconnects from the server and stops all further connection attempts, making `connect`
+    /// return.
+    pub fn stop(&self) {
+        self.is_stopped.store(true, Ordering::Relaxed);
+        if let Some(handler) = self.handler.read().as_ref() {
+            handler.stop();
+        }
+    }
+
+    /// Connects to the server and blocks until disconnected.
+    /// Returns `true` if the connection was fully established before it was lost.
+    fn connect_once(&self) -> bool {
         // Setup server.
         let (handler, listener) = node::split();
 
+        // Stored right away, so that `stop` can interrupt the connection attempt.
+        *self.server_endpoint.write() = None;
+        *self.handler.write() = Some(handler.to_owned());
+        if self.is_stopped.load(Ordering::Relaxed) {
+            return false;
+        }
+
         let server_address = if self.use_local_server {
             log!("## Development: Using local server at port 8391!");
             ozencstr!("0.0.0.0:8391")
@@ -192,19 +313,20 @@ impl CrossCom {
             .network()
             .connect(Transport::FramedTcp, server_address)
         {
-            crash!(
-                "[ERROR] Couldn't connect to server, report the following message: ",
-                error
-            );
+            log!("[ERROR] Couldn't connect to server, error: ", error);
+            return false;
         }
 
         self.set_state(CrossComState::Connecting);
-        listener.for_each(move |event| match event {
+        let was_connected = Cell::new(false);
+        listener.for_each(|event| match event {
             NodeEvent::Network(net_event) => match net_event {
-                NetEvent::Connected(endpoint, _) => {
-                    self.server_endpoint.get_or_init(|| endpoint);
-                    self.handler.get_or_init(|| handler.to_owned());
-
+                NetEvent::Connected(_, false) => {
+                    handler.stop();
+                    self.set_state(CrossComState::Disconnected);
+                }
+                NetEvent::Connected(endpoint, true) => {
+                    *self.server_endpoint.write() = Some(endpoint);
                     handler.signals().send(Signal::ConnectSignal);
                 }
                 NetEvent::Accepted(..) => unreachable!(),
@@ -220,6 +342,7 @@ impl CrossCom {
                     }
                 }
                 NetEvent::Disconnected(_) => {
+                    was_connected.set(self.get_state() == CrossComState::Connected);
                     self.set_state(CrossComState::Disconnected);
                 }
             },
@@ -238,14 +361,46 @@ impl CrossCom {
                             .to_owned(),
                     ));
                 }
+                Signal::Heartbeat => {
+                    if self.get_state() != CrossComState::Connected {
+                        return;
+                    }
+
+                    match self.ping_sent_at.get() {
+                        // Only consider the connection dead once the server has answered a ping,
+                        // otherwise servers without ping support would cause endless reconnects.
+                        Some(sent_at)
+                            if sent_at.elapsed() > PING_TIMEOUT && self.get_ping() >= 0 =>
+                        {
+                            log!("[SERVER] No response to ping, the connection is dead!");
+                            was_connected.set(true);
+                            handler.stop();
+                            self.set_state(CrossComState::Disconnected);
+                            return;
+                        }
+                        Some(_) => (),
+                        None => {
+                            self.ping_sent_at.set(Some(Instant::now()));
+                            self.send_data_type(DataType::Ping);
+                        }
+                    }
+
+                    handler
+                        .signals()
+                        .send_with_timer(Signal::Heartbeat, HEARTBEAT_INTERVAL);
+                }
             },
         });
+
+        was_connected.get()
     }
 
     /// Handles all server data types.
     fn handle_server_data(&self, server_data: CrossComServerData) {
         match server_data.data_type {
-            DataType::AuthSuccess => self.send_data_type(DataType::GetVersion(None)),
+            DataType::AuthSuccess => {
+                self.send_data_type(DataType::GetVersion(None));
+            }
             DataType::GetVersion(ref version) => {
                 if version.is_none() {
                     return;
@@ -259,6 +414,11 @@ impl CrossCom {
                     self.send_to_channel(server_data);
                 }
             }
+            DataType::BroadcastMessage(ref message) => {
+                if server_data.username.is_some() && !message.is_empty() {
+                    self.send_to_channel(server_data);
+                }
+            }
             DataType::ReceiveVariables(..)
             | DataType::CheckIsSerialOKResponse(..)
             | DataType::UpdateChannelSuccess
@@ -268,6 +428,12 @@ impl CrossCom {
                     self.send_to_channel(server_data)
                 }
             }
+            DataType::Pong => {
+                if let Some(sent_at) = self.ping_sent_at.take() {
+                    self.ping_rtt
+                        .store(sent_at.elapsed().as_millis() as i64, Ordering::Relaxed);
+                }
+            }
             DataType::ServerError(ref error) => crash!(error),
             _ => {
                 crash!("[SECURITY] Received an unknown data type, closing dynamic for your own safety.");
@@ -276,65 +442,102 @@ impl CrossCom {
     }
 
     /// Send a basic data type request.
-    pub fn send_data_type(&self, data_type: DataType) {
+    /// Returns `false` if the data wasn't sent.
+    pub fn send_data_type(&self, data_type: DataType) -> bool {
         match data_type {
+            DataType::SendScripts(ref payload) | DataType::BroadcastMessage(ref payload)
+                if payload.len() > self.max_payload_size =>
+            {
+                log!(
+                    "[ERROR] Payload is ",
+                    payload.len(),
+                    " bytes, which exceeds the max payload size of ",
+                    self.max_payload_size,
+                    " bytes. Not sending!"
+                );
+                false
+            }
             DataType::UpdateChannelSuccess
             | DataType::AuthSuccess
             | DataType::CheckIsSerialOKResponse(..)
             | DataType::ServerError(..)
             | DataType::SendFonts(..)
+            | DataType::Pong
             | DataType::ReceiveVariables(..) => {
                 crash!("[ERROR] Unsupported Data Type!")
             }
             _ => {
-                let server_endpoint = self
-                    .server_endpoint
-                    .get()
-                    .unwrap_or_crash(zencstr!("[ERROR] Server Endpoint hasn't been assigned!"));
-
-                let handler = self
-                    .handler
-                    .get()
-                    .unwrap_or_crash(zencstr!("[ERROR] Handler hasn't been assigned!"));
+                let (Some(server_endpoint), Some(handler)) =
+                    (*self.server_endpoint.read(), self.handler.read().to_owned())
+                else {
+                    log!("[ERROR] Not connected to the server, data wasn't sent!");
+                    return false;
+                };
 
                 let data = CrossComClientData {
                     username: self.username.to_owned(),
                     data_type,
                 }
                 .to_vec();
-                handler.network().send(*server_endpoint, &data);
+                handler.network().send(server_endpoint, &data);
+                true
             }
         }
     }
 
     /// Sends the specified Rune script.
     pub fn send_script(&self, source: &str) {
-        self.send_data_type(DataType::SendScripts(source.to_owned()));
-        log!("[PARTY] Sent script to channel members!");
+        if self.send_data_type(DataType::SendScripts(source.to_owned())) {
+            log!("[PARTY] Sent script to channel members!");
+        }
+    }
+
+    /// Sends a plain string message to all channel members.
+    pub fn send_message(&self, message: &str) {
+        self.send_data_type(DataType::BroadcastMessage(message.to_owned()));
     }
 
     /// Sends the specified data type and waits for a server message to be received, then
     /// passes it into `callback`.
     /// `callback` should return true/false for whether or not the message was the correct one or
     /// not.
-    fn send_and_wait<F: Fn(DataType) -> bool>(&self, send_data_type: DataType, callback: F) {
-        self.send_data_type(send_data_type);
+    /// Returns `false` if the data wasn't sent, or if the correct message wasn't received within
+    /// `RESPONSE_TIMEOUT`.
+    fn send_and_wait<F: Fn(DataType) -> bool>(
+        &self,
+        send_data_type: DataType,
+        callback: F,
+    ) -> bool {
+        if !self.send_data_type(send_data_type) {
+            return false;
+        }
 
+        let deadline = Instant::now() + RESPONSE_TIMEOUT;
         loop {
-            let Some(server_message) = self.get_network_listener().wait_for_message_raw() else {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() || IS_UNLOADING.load(Ordering::Relaxed) {
+                log!("[ERROR] The server didn't respond in time!");
+                return false;
+            }
+
+            let Some(server_message) = self
+                .get_network_listener()
+                .wait_for_message_raw_timeout(remaining)
+            else {
                 continue;
             };
 
             if callback(server_message.data_type) {
-                break;
+                return true;
             }
         }
     }
 
     /// Gets the server variables.
+    /// Returns an empty map if the server didn't respond.
     #[optimize(size)]
     pub fn get_variables(&self) -> HashMap<String, String> {
-        let mut result = OnceCell::new();
+        let result = OnceCell::new();
         self.send_and_wait(DataType::RequestVariables, |data_type| match data_type {
             DataType::ReceiveVariables(variables) => {
                 result.get_or_init(|| variables);
@@ -343,17 +546,14 @@ impl CrossCom {
             _ => false,
         });
 
-        std::mem::take(
-            result
-                .get_mut()
-                .unwrap_or_crash(zencstr!("[ERROR] No variables received!")),
-        )
+        result.into_inner().unwrap_or_default()
     }
 
     /// Gets the server community content.
+    /// Returns `None` if the server didn't respond.
     #[optimize(size)]
-    pub fn get_community_content(&self) -> Vec<CommunityItem> {
-        let mut result = OnceCell::new();
+    pub fn get_community_content(&self) -> Option<Vec<CommunityItem>> {
+        let result = OnceCell::new();
         self.send_and_wait(
             DataType::BroadcastCommunityContent(None),
             |data_type| match data_type {
@@ -369,11 +569,7 @@ impl CrossCom {
             },
         );
 
-        std::mem::take(
-            result
-                .get_mut()
-                .unwrap_or_crash(zencstr!("[ERROR] No community content received!")),
-        )
+        result.into_inner()
     }
 
     /// Requests to get the fonts used.
@@ -399,17 +595,26 @@ impl CrossCom {
     fn set_state(&self, state: CrossComState) {
         match state {
             CrossComState::Disconnected => {
-                if let Some(handler) = self.handler.get() {
+                *self.server_endpoint.write() = None;
+                if let Some(handler) = self.handler.write().take() {
                     handler.stop();
                 }
 
-                crash!("[SERVER] Disconnected from server, closing dynamic!");
+                log!("[SERVER] Disconnected from server!");
             }
             CrossComState::Connecting => {
                 log!("[SERVER] Connecting...");
             }
             CrossComState::Connected => {
                 log!("[SERVER] Connected!");
+                self.ping_sent_at.set(None);
+                if self.server_heartbeat
+                    && let Some(handler) = self.handler.read().as_ref()
+                {
+                    handler
+                        .signals()
+                        .send_with_timer(Signal::Heartbeat, HEARTBEAT_INTERVAL);
+                }
             }
         }
 
@@ -421,6 +626,12 @@ impl CrossCom {
         self.state.get()
     }
 
+    /// Gets the round-trip time of the last answered ping in milliseconds, or `-1` if no ping has
+    /// been answered yet or `server_heartbeat` is disabled.
+    pub fn get_ping(&self) -> i64 {
+        self.ping_rtt.load(Ordering::Relaxed)
+    }
+
     /// Sends a message to the CrossCom channel.
     pub fn send_to_channel(&self, data: CrossComServerData) {
         let Err(error) = self
@@ -435,6 +646,12 @@ impl CrossCom {
         log!("[ERROR] Failed sending channel message, error: ", error);
     }
 
+    /// Checks if `channel` is a valid channel name.
+    /// It has to start with `#`, contain no whitespaces and be at least 4 characters long.
+    pub fn is_valid_channel(channel: &str) -> bool {
+        channel.starts_with('#') && !channel.contains(' ') && channel.len() >= 4
+    }
+
     /// Tries to join the specified channel.
     pub fn join_channel(&self, mut channel: String) {
         if self.has_pending_channel_update.load(Ordering::Relaxed) {
@@ -456,11 +673,7 @@ impl CrossCom {
             return;
         };
 
-        if current_channel.eq_ignore_ascii_case(&channel)
-            || !channel.starts_with('#')
-            || channel.contains(' ')
-            || channel.len() < 4
-        {
+        if current_channel.eq_ignore_ascii_case(&channel) || !Self::is_valid_channel(&channel) {
             log!("[ERROR] You are either already in the specified channel, or its invalid!");
             return;
         }
@@ -473,6 +686,7 @@ impl CrossCom {
         channel.truncate(64);
 
         *current_channel = channel;
+        let channel = current_channel.to_owned();
         self.send_data_type(DataType::UpdateChannel(current_channel.to_owned()));
         drop(current_channel);
 
@@ -484,6 +698,8 @@ impl CrossCom {
             log!("[PARTY] Joined channel!");
             self.has_pending_channel_update
                 .store(false, Ordering::Relaxed);
+            self.get_network_listener()
+                .call_channel_joined_callbacks(channel);
             return;
         }
 
@@ -501,27 +717,19 @@ impl CrossCom {
         bearer_token: String,
         serials: Arc<Vec<String>>,
     ) -> bool {
-        self.send_data_type(DataType::CheckIsSerialOK(
-            product_id,
-            bearer_token,
-            (*serials).to_owned(),
-        ));
-
-        loop {
-            let Some(server_message) = self.get_network_listener().wait_for_message_raw() else {
-                continue;
-            };
-
-            match server_message.data_type {
+        let is_ok = Cell::new(false);
+        self.send_and_wait(
+            DataType::CheckIsSerialOK(product_id, bearer_token, (*serials).to_owned()),
+            |data_type| match data_type {
                 DataType::CheckIsSerialOKResponse(success) => {
-                    return success;
-                }
-                _ => {
-                    drop(server_message);
-                    continue;
+                    is_ok.set(success);
+                    true
                 }
-            }
-        }
+                _ => false,
+            },
+        );
+
+        is_ok.get()
     }
 
     /// Gets the current channel. Do **not** modify it as-is, use `self.join_channel()`!
@@ -9,6 +9,10 @@ use zstring::ZString;
 pub enum WidgetType {
     Label(ZString, usize),
     LabelCustomFont(String, Arc<String>),
+
+    /// Label which wraps its text at the given width. A width of `0.0` or less wraps at the end
+    /// of the window.
+    LabelWrapped(ZString, f32, usize),
     Button(ZString, Rc<SyncFunction>, Rc<Option<Value>>),
     Spacing(f32, f32),
     Separator,
@@ -51,10 +55,42 @@ pub enum WidgetType {
         Rc<SyncFunction>,
         Rc<Option<Value>>,
     ),
+    RadioGroup(
+        ZString,
+        usize,
+        Vec<String>,
+        Rc<SyncFunction>,
+        Rc<Option<Value>>,
+    ),
+    ProgressBar(f32, Option<String>),
+    ColorPicker(ZString, [f32; 4], bool, Rc<SyncFunction>, Rc<Option<Value>>),
+    ListBox(
+        ZString,
+        Vec<bool>,
+        Vec<String>,
+        Rc<SyncFunction>,
+        Rc<Option<Value>>,
+    ),
+    InputText(ZString, String, usize, Rc<SyncFunction>, Rc<Option<Value>>),
+    /// Same as `InputText` but with its content hidden, only readable through
+    /// `get_password_value`.
+    Password(ZString, String, Rc<SyncFunction>, Rc<Option<Value>>),
+    PlotLines(ZString, Vec<f32>, f32, f32),
+
+    /// Bordered, scrollable panel of text lines with the given height, which keeps scrolling to
+    /// the newest line while scrolled to the bottom.
+    LogPanel(Vec<String>, f32),
+
+    /// Table with the given column headers and rows of cells.
+    Table(Vec<String>, Vec<Vec<String>>),
 }
 
 /// Sub-widget type, aka types like collapsing headers and alike.
 #[derive(Clone)]
 pub enum SubWidgetType {
     CollapsingHeader(ZString),
+
+    /// Tab bar, should only contain `TabItem` sub-widgets.
+    TabBar,
+    TabItem(ZString),
 }
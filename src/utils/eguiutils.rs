@@ -1,6 +1,9 @@
 use super::crosscom::CrossCom;
 use crate::{
-    globals::LOGGED_MESSAGES,
+    globals::{
+        LOGGED_MESSAGES, SIDE_MESSAGE_COLOR, SIDE_MESSAGE_POS, SOFTWARE_CURSOR_COLOR,
+        SOFTWARE_CURSOR_SIZE,
+    },
     utils::{
         colorutils::ColorUtils,
         config::Config,
@@ -10,10 +13,15 @@ use crate::{
 };
 use dashmap::DashMap;
 use hudhook::imgui::{self, internal::DataTypeKind, sys::*, *};
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
 use std::{
     fs::File,
-    sync::{Arc, LazyLock},
+    sync::{
+        atomic::Ordering,
+        mpsc::{Receiver, Sender},
+        Arc, LazyLock,
+    },
+    time::{Duration, Instant},
 };
 use windows::Win32::Foundation::POINT;
 
@@ -21,6 +29,9 @@ use windows::Win32::Foundation::POINT;
 pub struct CustomTexture {
     pub texture_type: CustomTextureType,
     pub texture_id: Option<TextureId>,
+
+    /// Natural width and height of the image, `[0, 0]` until it has been uploaded.
+    pub size: [u32; 2],
 }
 
 /// Type for `CustomTexture`.
@@ -29,6 +40,9 @@ pub enum CustomTextureType {
     Singular,
     Gif,
     GifFrame,
+
+    /// Image decoded from raw bytes rather than loaded from a file.
+    Memory,
 }
 
 #[derive(Default)]
@@ -37,6 +51,72 @@ pub struct ContentFrameData {
     pub titles: Vec<String>,
 }
 
+/// An input prompt opened by a script, waiting for the user to answer it.
+struct InputPrompt {
+    /// Title of the modal, also used as its ID.
+    title: String,
+
+    /// Label displayed above the input field.
+    label: String,
+
+    /// Current content of the input field.
+    input: String,
+
+    /// Has the modal been opened yet?
+    opened: bool,
+
+    /// Sender for the answer, `None` if the prompt was cancelled.
+    sender: Sender<Option<String>>,
+}
+
+/// Work queued by scripts for the render thread.
+/// Kept outside of `ImGuiUtils` so that scripts never have to lock it, as the render thread may
+/// hold onto it for the entire frame.
+#[derive(Default)]
+pub struct ScriptUiQueues {
+    /// Fonts registered at runtime which haven't been loaded yet, key being the relative path and
+    /// the value being the font size.
+    pending_fonts: DashMap<String, f32>,
+
+    /// Active toasts, each entry being the text and when the toast expires.
+    toasts: Mutex<Vec<(String, Instant)>>,
+
+    /// Pending input prompts, only the first one is displayed at a time.
+    prompts: Mutex<Vec<InputPrompt>>,
+}
+
+impl ScriptUiQueues {
+    /// Queues a font to be loaded before the next frame is rendered.
+    pub fn register_font(&self, relative_font_path: String, font_size: f32) {
+        self.pending_fonts.insert(relative_font_path, font_size);
+    }
+
+    /// Displays `text` as a toast in the top-right corner for `seconds` seconds.
+    pub fn add_toast(&self, text: String, seconds: f32) {
+        let Ok(duration) = Duration::try_from_secs_f32(seconds) else {
+            log!("[ERROR] Invalid toast duration: ", seconds, "!");
+            return;
+        };
+
+        self.toasts.lock().push((text, Instant::now() + duration));
+    }
+
+    /// Queues an input prompt and returns the receiver for its answer.
+    /// The answer is `None` if the user cancelled the prompt.
+    pub fn add_prompt(&self, title: String, label: String) -> Receiver<Option<String>> {
+        let (sender, receiver) = std::sync::mpsc::channel();
+        self.prompts.lock().push(InputPrompt {
+            title,
+            label,
+            input: String::new(),
+            opened: false,
+            sender,
+        });
+
+        receiver
+    }
+}
+
 /// Highly experimental ImGui Utils.
 pub struct ImGuiUtils {
     /// Enable side messages?
@@ -45,13 +125,17 @@ pub struct ImGuiUtils {
     /// A map to keep track of the custom-added fonts, so we can use the relative path to identify
     /// them.
     pub fonts: DashMap<Arc<String>, usize>,
+
+    /// Work queued by scripts.
+    script_ui_queues: &'static ScriptUiQueues,
 }
 
 impl ImGuiUtils {
-    pub fn new() -> Self {
+    pub fn new(script_ui_queues: &'static ScriptUiQueues) -> Self {
         Self {
             enable_side_messages: true,
             fonts: DashMap::new(),
+            script_ui_queues,
         }
     }
 
@@ -113,36 +197,96 @@ impl ImGuiUtils {
         };
 
         for (relative_font_path, font_size) in custom_fonts {
-            ctx.fonts().add_font(&[FontSource::TtfData {
-                data: &config
+            self.add_custom_font(
+                ctx,
+                relative_font_path,
+                &config
                     .get_file_content_bytes(relative_font_path)
                     .dynamic_expect(zencstr!(
                         "Failed reading Font Data from config.jsonc -> fonts -> ",
                         relative_font_path
                     )),
-                size_pixels: font_size,
-                config: Some(imgui::FontConfig {
-                    oversample_h: 4,
-                    oversample_v: 4,
-                    glyph_ranges: glyph_ranges.to_owned(),
-                    ..imgui::FontConfig::default()
-                }),
-            }]);
-
-            log!(
-                "[FONTS] Installed Font from relative path \"",
-                relative_font_path,
-                "\", size: ",
                 font_size,
-                "."
-            );
-            self.fonts.insert(
-                Arc::new(relative_font_path.to_owned()),
-                ctx.fonts().fonts().len() - 1,
             );
         }
     }
 
+    /// Adds a custom font into the font atlas and stores its index in `self.fonts`, so that it
+    /// can be identified by `relative_font_path`.
+    fn add_custom_font(
+        &self,
+        ctx: &mut imgui::Context,
+        relative_font_path: &str,
+        font_data: &[u8],
+        font_size: f32,
+    ) {
+        // https://github.com/ryanoasis/nerd-fonts/wiki/Glyph-Sets-and-Code-Points
+        let glyph_ranges = imgui::FontGlyphRanges::from_slice(&[0xf0001, 0xf1af0, 0x1, 0x1FFFF, 0]);
+        ctx.fonts().add_font(&[FontSource::TtfData {
+            data: font_data,
+            size_pixels: font_size,
+            config: Some(imgui::FontConfig {
+                oversample_h: 4,
+                oversample_v: 4,
+                glyph_ranges,
+                ..imgui::FontConfig::default()
+            }),
+        }]);
+
+        log!(
+            "[FONTS] Installed Font from relative path \"",
+            relative_font_path,
+            "\", size: ",
+            font_size,
+            "."
+        );
+        self.fonts.insert(
+            Arc::new(relative_font_path.to_owned()),
+            ctx.fonts().fonts().len() - 1,
+        );
+    }
+
+    /// Loads all fonts registered through `ScriptUiQueues::register_font` and rebuilds the font
+    /// atlas.
+    pub fn load_pending_fonts(&self, ctx: &mut imgui::Context, config: &Config) {
+        let queued_fonts = &self.script_ui_queues.pending_fonts;
+        if queued_fonts.is_empty() {
+            return;
+        }
+
+        let pending_fonts = queued_fonts
+            .iter()
+            .map(|entry| (entry.key().to_owned(), *entry.value()))
+            .collect::<Vec<(String, f32)>>();
+        queued_fonts.clear();
+
+        for (relative_font_path, font_size) in pending_fonts {
+            if self.fonts.contains_key(&relative_font_path) {
+                log!(
+                    "[ERROR] A font with the relative path of \"",
+                    relative_font_path,
+                    "\" has already been installed!"
+                );
+                continue;
+            }
+
+            match config.get_file_content_bytes(&relative_font_path) {
+                Ok(font_data) => {
+                    self.add_custom_font(ctx, &relative_font_path, &font_data, font_size)
+                }
+                Err(error) => log!(
+                    "[ERROR] Failed reading Font Data from \"",
+                    relative_font_path,
+                    "\", error: ",
+                    error
+                ),
+            }
+        }
+
+        ctx.fonts().build_rgba32_texture();
+        log!("[FONTS] Font atlas rebuilt. If text turns into blocks, the renderer couldn't re-upload the atlas and dynamic has to be re-injected for the fonts to work.");
+    }
+
     /// Applies the custom theme.
     pub fn apply_style(
         &self,
@@ -223,11 +367,14 @@ impl ImGuiUtils {
 
         static WHITE: [f32; 4] = [1.0; 4];
 
+        let color = *SOFTWARE_CURSOR_COLOR.get().unwrap_or(&WHITE);
+        let size = SOFTWARE_CURSOR_SIZE.load(Ordering::Relaxed);
+
         WinUtils::get_cursor_pos_recycle(point);
         let draw_list = ui.get_foreground_draw_list();
         let mouse_pos = [point.x as f32, point.y as f32];
         draw_list
-            .add_rect(mouse_pos, [mouse_pos[0] + 5.0, mouse_pos[1] + 5.0], WHITE)
+            .add_rect(mouse_pos, [mouse_pos[0] + size, mouse_pos[1] + size], color)
             .filled(true)
             .rounding(5.0)
             .build();
@@ -291,9 +438,122 @@ impl ImGuiUtils {
         };
 
         let draw = ui.get_background_draw_list();
-        static DRAW_POS: [f32; 2] = [0.0, 100.0];
+        static DEFAULT_POS: [f32; 2] = [0.0, 100.0];
         static WHITE: [f32; 4] = [1.0; 4];
-        draw.add_text(DRAW_POS, WHITE, &*logged_messages);
+        draw.add_text(
+            *SIDE_MESSAGE_POS.get().unwrap_or(&DEFAULT_POS),
+            *SIDE_MESSAGE_COLOR.get().unwrap_or(&WHITE),
+            &*logged_messages,
+        );
+    }
+
+    /// Draws all active toasts in the top-right corner, fading them out before they expire.
+    /// Expired toasts are removed.
+    pub fn draw_toasts(&self, ui: &imgui::Ui) {
+        let Some(mut toasts) = self.script_ui_queues.toasts.try_lock() else {
+            return;
+        };
+
+        let now = Instant::now();
+        toasts.retain(|(_, expiry)| *expiry > now);
+        if toasts.is_empty() {
+            return;
+        }
+
+        static MARGIN: f32 = 10.0;
+        static PADDING: f32 = 6.0;
+        static FADE_SECONDS: f32 = 0.5;
+
+        let draw = ui.get_foreground_draw_list();
+        let display_width = ui.io().display_size[0];
+        let mut y = MARGIN;
+
+        for (text, expiry) in toasts.iter() {
+            let alpha = ((*expiry - now).as_secs_f32() / FADE_SECONDS).min(1.0);
+            let text_size = ui.calc_text_size(text);
+            let min = [display_width - text_size[0] - PADDING * 2.0 - MARGIN, y];
+            let max = [display_width - MARGIN, y + text_size[1] + PADDING * 2.0];
+
+            draw.add_rect(min, max, [0.0, 0.0, 0.0, 0.7 * alpha])
+                .filled(true)
+                .rounding(4.0)
+                .build();
+            draw.add_text(
+                [min[0] + PADDING, min[1] + PADDING],
+                [1.0, 1.0, 1.0, alpha],
+                text,
+            );
+
+            y = max[1] + PADDING;
+        }
+    }
+
+    /// Draws the first pending input prompt as a modal, sending the answer once it's submitted
+    /// or cancelled.
+    pub fn draw_prompts(&self, ui: &imgui::Ui) {
+        let Some(mut prompts) = self.script_ui_queues.prompts.try_lock() else {
+            return;
+        };
+
+        let Some(prompt) = prompts.first_mut() else {
+            return;
+        };
+
+        if !prompt.opened {
+            ui.open_popup(&prompt.title);
+            prompt.opened = true;
+        }
+
+        let mut answer = None;
+        ui.modal_popup_config(&prompt.title)
+            .always_auto_resize(true)
+            .build(|| {
+                ui.text(&prompt.label);
+                let submitted = ui
+                    .input_text("##prompt_input", &mut prompt.input)
+                    .enter_returns_true(true)
+                    .build();
+
+                if ui.button(zencstr!("OK").data) || submitted {
+                    answer = Some(Some(std::mem::take(&mut prompt.input)));
+                    ui.close_current_popup();
+                }
+
+                ui.same_line();
+                if ui.button(zencstr!("Cancel").data) {
+                    answer = Some(None);
+                    ui.close_current_popup();
+                }
+            });
+
+        if let Some(answer) = answer {
+            // The receiver may already be gone if the script stopped, nothing to do then.
+            let _ = prompts.remove(0).sender.send(answer);
+        }
+    }
+
+    /// Draws `messages` inside of a bordered, scrollable child window.
+    /// If the panel is scrolled to the bottom, it stays there as new messages are added.
+    /// A `height` of `0.0` fills the remaining height of the window.
+    pub fn draw_log_panel<S: AsRef<str>>(
+        ui: &imgui::Ui,
+        identifier: &str,
+        messages: &[S],
+        height: f32,
+    ) {
+        ui.child_window(identifier)
+            .size([0.0, height])
+            .border(true)
+            .build(|| {
+                let is_at_bottom = ui.scroll_y() >= ui.scroll_max_y();
+                for message in messages {
+                    ui.text_wrapped(message);
+                }
+
+                if is_at_bottom {
+                    ui.set_scroll_here_y_with_ratio(1.0);
+                }
+            });
     }
 
     /// Draws an image onto the UI in form of a `image_button` without any styling but the image
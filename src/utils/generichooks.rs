@@ -0,0 +1,167 @@
+use parking_lot::Mutex;
+use retour::static_detour;
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc, Once,
+};
+use windows::Win32::{
+    Foundation::{BOOL, POINT},
+    UI::WindowsAndMessaging::GetCursorPos,
+};
+
+use crate::winutils::WinUtils;
+
+static_detour! {
+    static SetCursorPosHook: unsafe extern "system" fn(i32, i32) -> BOOL;
+    static GetCursorPosHook: unsafe extern "system" fn(*mut POINT) -> BOOL;
+}
+
+/// Generic WinAPI hooks which aren't tied to any specific game.
+pub struct GenericHoooks {
+    /// If `true`, calls to `SetCursorPos` are silently ignored.
+    disable_set_cursor_pos: Arc<AtomicBool>,
+
+    /// If `true`, calls to `GetCursorPos` return `frozen_cursor_pos`.
+    freeze_cursor: Arc<AtomicBool>,
+
+    /// Cursor position captured when the cursor was frozen.
+    frozen_cursor_pos: Arc<Mutex<POINT>>,
+
+    /// Installs `SetCursorPosHook` the first time the cursor lock is changed.
+    set_cursor_pos_hook: Once,
+
+    /// Installs `GetCursorPosHook` the first time the cursor is frozen.
+    get_cursor_pos_hook: Once,
+}
+
+impl GenericHoooks {
+    /// Returns an instance of `Self`.
+    /// The hooks aren't installed until a script first needs them, so that processes which
+    /// never use them are left untouched.
+    pub fn init() -> Self {
+        Self {
+            disable_set_cursor_pos: Arc::new(AtomicBool::new(false)),
+            freeze_cursor: Arc::new(AtomicBool::new(false)),
+            frozen_cursor_pos: Arc::new(Mutex::new(POINT::default())),
+            set_cursor_pos_hook: Once::new(),
+            get_cursor_pos_hook: Once::new(),
+        }
+    }
+
+    /// Hooks `SetCursorPos` so that it can be blocked at runtime.
+    fn hook_set_cursor_pos(&self) {
+        let Some(address) =
+            WinUtils::get_module_symbol_address(&zencstr!("user32.dll").data, c"SetCursorPos")
+        else {
+            log!("[ERROR] Couldn't find SetCursorPos, cursor lock control is unavailable!");
+            return;
+        };
+
+        let disable_set_cursor_pos = Arc::clone(&self.disable_set_cursor_pos);
+        let result = unsafe {
+            let target: unsafe extern "system" fn(i32, i32) -> BOOL = std::mem::transmute(address);
+            SetCursorPosHook
+                .initialize(target, move |x, y| {
+                    if disable_set_cursor_pos.load(Ordering::Relaxed) {
+                        return BOOL(1);
+                    }
+
+                    SetCursorPosHook.call(x, y)
+                })
+                .and_then(|hook| hook.enable())
+        };
+
+        if let Err(error) = result {
+            log!("[ERROR] Failed hooking SetCursorPos, error: ", error);
+        }
+    }
+
+    /// Hooks `GetCursorPos` so that it can return a frozen point at runtime.
+    fn hook_get_cursor_pos(&self) {
+        let Some(address) =
+            WinUtils::get_module_symbol_address(&zencstr!("user32.dll").data, c"GetCursorPos")
+        else {
+            log!("[ERROR] Couldn't find GetCursorPos, cursor freezing is unavailable!");
+            return;
+        };
+
+        let freeze_cursor = Arc::clone(&self.freeze_cursor);
+        let frozen_cursor_pos = Arc::clone(&self.frozen_cursor_pos);
+        let result = unsafe {
+            let target: unsafe extern "system" fn(*mut POINT) -> BOOL =
+                std::mem::transmute(address);
+            GetCursorPosHook
+                .initialize(target, move |point| {
+                    if freeze_cursor.load(Ordering::Relaxed) && !point.is_null() {
+                        *point = *frozen_cursor_pos.lock();
+                        return BOOL(1);
+                    }
+
+                    GetCursorPosHook.call(point)
+                })
+                .and_then(|hook| hook.enable())
+        };
+
+        if let Err(error) = result {
+            log!("[ERROR] Failed hooking GetCursorPos, error: ", error);
+        }
+    }
+
+    /// Disables all generic hooks, used when unloading.
+    pub fn unhook_all() {
+        unsafe {
+            if SetCursorPosHook.is_enabled() {
+                let _ = SetCursorPosHook.disable();
+            }
+
+            if GetCursorPosHook.is_enabled() {
+                let _ = GetCursorPosHook.disable();
+            }
+        }
+    }
+
+    /// Gets the real cursor position, bypassing `GetCursorPosHook` if it's active.
+    pub fn get_real_cursor_pos(point: &mut POINT) -> windows::core::Result<()> {
+        unsafe {
+            if GetCursorPosHook.is_enabled() {
+                return GetCursorPosHook.call(point).ok();
+            }
+
+            GetCursorPos(point)
+        }
+    }
+
+    /// Allows or blocks the game from moving the cursor through `SetCursorPos`.
+    pub fn set_cursor_lock(&self, enabled: bool) {
+        self.set_cursor_pos_hook
+            .call_once(|| self.hook_set_cursor_pos());
+        self.disable_set_cursor_pos
+            .store(!enabled, Ordering::Relaxed);
+    }
+
+    /// Freezes `GetCursorPos` at the current cursor position, or unfreezes it.
+    pub fn freeze_cursor(&self, frozen: bool) {
+        if frozen {
+            self.get_cursor_pos_hook
+                .call_once(|| self.hook_get_cursor_pos());
+            if !unsafe { GetCursorPosHook.is_enabled() } {
+                return;
+            }
+        }
+
+        if frozen && !self.freeze_cursor.load(Ordering::Relaxed) {
+            let mut point = POINT::default();
+            if let Err(error) = Self::get_real_cursor_pos(&mut point) {
+                log!(
+                    "[ERROR] Failed getting the cursor position to freeze at, error: ",
+                    error
+                );
+                return;
+            }
+
+            *self.frozen_cursor_pos.lock() = point;
+        }
+
+        self.freeze_cursor.store(frozen, Ordering::Relaxed);
+    }
+}
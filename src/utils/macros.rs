@@ -76,12 +76,11 @@ macro_rules! crash {
     }};
     ($($arg:expr),*) => {
         {
-            print!("{}", zencstr!("[", file!(), ":", line!(), "]: "));
-            let mut message = $crate::zstring::ZString::default();
+            let mut message = zencstr!("[", file!(), ":", line!(), "]: ");
             $(
                 $crate::utils::stringutils::StringUtils::crash_helper_append(&mut message, encrypt_arg!($arg));
             )*
-            println!();
+            $crate::winutils::WinUtils::log_message($crate::zstring::ZString::new(message.data.to_owned()), true);
             $crate::winutils::WinUtils::display_message_box(&zencstr!("dynamic").data, &message.data, 0x00000010);
             $crate::winutils::WinUtils::sleep_and_exit(5)
         }
@@ -7,6 +7,7 @@ pub mod cryptutils;
 pub mod dynwidget;
 pub mod eguiutils;
 pub mod extensions;
+pub mod generichooks;
 pub mod network;
 pub mod prompter;
 pub mod scripting;
@@ -1,12 +1,28 @@
-use crate::utils::crosscom::{CrossCom, CrossComServerData, DataType};
+use crate::{
+    globals::IS_UNLOADING,
+    utils::crosscom::{CrossCom, CrossComServerData, DataType},
+    winutils::WinUtils,
+};
 use crossbeam_channel::{Receiver, Sender};
 use parking_lot::RwLock;
-use std::sync::{Arc, LazyLock};
+use std::{
+    sync::{atomic::Ordering, Arc, LazyLock},
+    time::Duration,
+};
+
+/// Callback which receives a string from a network event.
+pub type NetworkCallback = Arc<dyn Fn(String) + Send + Sync>;
 
 /// Network Listener utility.
 pub struct NetworkListener {
     /// Channel that receives server messages from across `crosscom.rs`.
     crossbeam_channel: Arc<LazyLock<(Sender<CrossComServerData>, Receiver<CrossComServerData>)>>,
+
+    /// Callbacks called when a `BroadcastMessage` is received.
+    group_message_callbacks: Arc<RwLock<Vec<NetworkCallback>>>,
+
+    /// Callbacks called after a channel has been joined successfully.
+    channel_joined_callbacks: RwLock<Vec<NetworkCallback>>,
 }
 
 impl NetworkListener {
@@ -16,6 +32,8 @@ impl NetworkListener {
             crossbeam_channel: Arc::new(LazyLock::new(
                 crossbeam_channel::unbounded::<CrossComServerData>,
             )),
+            group_message_callbacks: Arc::default(),
+            channel_joined_callbacks: RwLock::default(),
         }
     }
 
@@ -33,6 +51,11 @@ impl NetworkListener {
         Self::internal_wait_for_message_raw(Arc::clone(&self.crossbeam_channel))
     }
 
+    /// Same as `wait_for_message_raw`, but gives up and returns `None` after `timeout`.
+    pub fn wait_for_message_raw_timeout(&self, timeout: Duration) -> Option<CrossComServerData> {
+        self.crossbeam_channel.1.recv_timeout(timeout).ok()
+    }
+
     /// Waits for the Crossbeam channel to receive an instance of `CrossComServerData`, with a
     /// specific simple data type.
     pub fn wait_for_message(&self, data_type: DataType) -> Option<CrossComServerData> {
@@ -46,17 +69,18 @@ impl NetworkListener {
         None
     }
 
-    /// Hooks the `SendScripts` Data Type.
+    /// Hooks the `SendScripts` and `BroadcastMessage` Data Types.
     pub fn hook_on_script_received<F: Fn(String) + Send + 'static>(
         &self,
         crosscom: Arc<RwLock<CrossCom>>,
         callback: F,
     ) {
         let crossbeam_chanel = Arc::clone(&self.crossbeam_channel);
-        std::thread::spawn(move || {
-            loop {
-                let Some(message) =
-                    Self::internal_wait_for_message_raw(Arc::clone(&crossbeam_chanel))
+        let group_message_callbacks = Arc::clone(&self.group_message_callbacks);
+        WinUtils::spawn_worker(move || {
+            while !IS_UNLOADING.load(Ordering::Relaxed) {
+                // Timing out regularly, so that unloading isn't blocked by an idle channel.
+                let Ok(message) = crossbeam_chanel.1.recv_timeout(Duration::from_millis(100))
                 else {
                     continue;
                 };
@@ -67,6 +91,14 @@ impl NetworkListener {
                             log!("Server Group: Incoming script, executing!");
                             callback(script);
                         }
+                        DataType::BroadcastMessage(message) => {
+                            // Clone the callbacks so new ones can be registered from inside of
+                            // them.
+                            let group_message_callbacks = group_message_callbacks.read().to_owned();
+                            for group_message_callback in group_message_callbacks {
+                                group_message_callback(message.to_owned());
+                            }
+                        }
                         _ => {
                             // Not SendScripts, send the message back to Crossbeam.
                             crosscom.send_to_channel(message);
@@ -79,6 +111,36 @@ impl NetworkListener {
         });
     }
 
+    /// Registers a callback which is called with the content of every `BroadcastMessage`
+    /// received.
+    pub fn on_group_message<F: Fn(String) + Send + Sync + 'static>(&self, callback: F) {
+        self.group_message_callbacks
+            .write()
+            .push(Arc::new(callback));
+    }
+
+    /// Registers a callback which is called with the name of the channel, every time a channel
+    /// has been joined.
+    pub fn on_channel_joined<F: Fn(String) + Send + Sync + 'static>(&self, callback: F) {
+        self.channel_joined_callbacks
+            .write()
+            .push(Arc::new(callback));
+    }
+
+    /// Calls all channel-joined callbacks on a new thread, so that the caller isn't blocked.
+    pub fn call_channel_joined_callbacks(&self, channel: String) {
+        let channel_joined_callbacks = self.channel_joined_callbacks.read().to_owned();
+        if channel_joined_callbacks.is_empty() {
+            return;
+        }
+
+        WinUtils::spawn_worker(move || {
+            for channel_joined_callback in channel_joined_callbacks {
+                channel_joined_callback(channel.to_owned());
+            }
+        });
+    }
+
     /// Gets the crossbeam channel.
     pub fn get_crossbeam_channel(
         &self,
@@ -1,3 +1,4 @@
+use std::sync::mpsc::Receiver;
 use zstring::ZString;
 
 /// Basic CLI Prompter.
@@ -12,6 +13,19 @@ pub struct PromptResult {
     pub args: Vec<String>,
 }
 
+impl PromptResult {
+    /// Joins the prompt and its arguments back into one response, separated by spaces.
+    pub fn into_response(mut self) -> String {
+        let mut response = std::mem::take(&mut self.prompt.data);
+        for arg in self.args.iter().skip(1) {
+            response.push(' ');
+            response.push_str(arg);
+        }
+
+        response
+    }
+}
+
 impl<'a> Prompter<'a> {
     /// Creates a new prompt which only classifies certain responses as valid.
     pub const fn new(message: &'a str, valid_responses: Vec<&'a str>) -> Self {
@@ -38,6 +52,20 @@ impl<'a> Prompter<'a> {
             .read_line(&mut input.data)
             .unwrap_or_default();
 
+        self.parse(input)
+    }
+
+    /// Prompts the user, but waits for the line to be sent through `receiver` rather than reading
+    /// it from `stdin`.
+    /// Used when `stdin` is already being read by someone else, like the console command loop.
+    /// Returns `None` if the sender has been dropped.
+    pub fn prompt_from(&mut self, receiver: &Receiver<String>) -> Option<PromptResult> {
+        log!(self.message);
+        self.parse(ZString::new(receiver.recv().ok()?))
+    }
+
+    /// Splits `input` into the prompt and its arguments, then checks if it's valid.
+    fn parse(&self, mut input: ZString) -> Option<PromptResult> {
         // Remove new lines and carriage return symbols.
         input.data = input.data.replace(['\n', '\r'], "");
 
@@ -7,11 +7,20 @@ use rune::{
     runtime::{Function, SyncFunction},
     Value,
 };
-use std::sync::{atomic::AtomicUsize, Arc};
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
 
 /// Amount of parameters to collect from hooked functions.
 pub static COLLECT_PARAMS_COUNT: AtomicUsize = const { AtomicUsize::new(10) };
 
+/// Compile-time upper bound of how many RDetours that can exist at once.
+pub const MAX_DETOURS: usize = 32;
+
+/// Amount of RDetours to register, capped at `MAX_DETOURS`.
+pub static DETOUR_POOL_SIZE: AtomicUsize = const { AtomicUsize::new(15) };
+
 /// All Rune detours, acquired and non-acquired.
 static RUNE_DETOURS: RwLock<Vec<Arc<AtomicRefCell<RDetour>>>> = const { RwLock::new(Vec::new()) };
 
@@ -28,8 +37,7 @@ macro_rules! generate_detour_id {
             RDetour::register_new_detour($id);
         });
 
-        let mut collected_args =
-            Vec::with_capacity(COLLECT_PARAMS_COUNT.load(std::sync::atomic::Ordering::Relaxed));
+        let mut collected_args = Vec::with_capacity(COLLECT_PARAMS_COUNT.load(Ordering::Relaxed));
         for _ in 0..collected_args.capacity() {
             collected_args.push(unsafe { $args.arg::<*const i64>() } as i64);
         }
@@ -48,24 +56,16 @@ macro_rules! generate_detour_holder {
     };
 }
 
-/// All detour holder function pointers.
-const HOLDERS: [*const (); 15] = [
-    detour_holder_00 as *const (),
-    detour_holder_01 as *const (),
-    detour_holder_02 as *const (),
-    detour_holder_03 as *const (),
-    detour_holder_04 as *const (),
-    detour_holder_05 as *const (),
-    detour_holder_06 as *const (),
-    detour_holder_07 as *const (),
-    detour_holder_08 as *const (),
-    detour_holder_09 as *const (),
-    detour_holder_10 as *const (),
-    detour_holder_11 as *const (),
-    detour_holder_12 as *const (),
-    detour_holder_13 as *const (),
-    detour_holder_14 as *const (),
-];
+/// Generates all detour holder functions, and the `HOLDERS` array containing pointers to them.
+/// The amount of holders **must** be equal to `MAX_DETOURS`.
+macro_rules! generate_detour_holders {
+    ($($fn_name:ident => $id:literal),* $(,)?) => {
+        $(generate_detour_holder!($fn_name, $id);)*
+
+        /// All detour holder function pointers.
+        const HOLDERS: [*const (); MAX_DETOURS] = [$($fn_name as *const ()),*];
+    };
+}
 
 /// Holds the information about a Rune detour.
 #[derive(Default)]
@@ -90,13 +90,21 @@ pub struct RDetour {
     /// Optional paramater to be passed into `rune_function` upon callback.
     /// Can be a structure for example, so that variables can be updated.
     opt_param: Option<ValueWrapper>,
+
+    /// Optional name of the detour, used by `drop_rdetour_named`.
+    name: Option<String>,
+
+    /// If `true`, the original function is never called and `rune_function` receives a null
+    /// trampoline. The returned value is used directly as the replacement return value.
+    skip_original: bool,
 }
 
 impl RDetour {
     /// Calls all `detour_holder_xx` functions in order for each one to register itself via `register_new_detour`.
     pub fn register_all_detours() {
+        let pool_size = DETOUR_POOL_SIZE.load(Ordering::Relaxed).min(MAX_DETOURS);
         unsafe {
-            for holder in HOLDERS {
+            for holder in HOLDERS.into_iter().take(pool_size) {
                 // Safety: The function is transmuted to the same style as the generated one,
                 // making it safe to call.
                 std::mem::transmute::<*const (), unsafe extern "C" fn(...) -> *const i64>(holder)();
@@ -147,6 +155,51 @@ impl RDetour {
     /// Automatically finds the first-available `RDetour` and installs it on `from_ptr` with the
     /// callback function of `rune_function`.
     pub fn install_detour_auto(from_ptr: i64, rune_function: Function, opt_param: Option<Value>) {
+        Self::install_detour_on_free(from_ptr, None, false, rune_function, opt_param);
+    }
+
+    /// Same as `install_detour_auto`, but the original function is skipped entirely and the value
+    /// returned by `rune_function` is used as the replacement return value.
+    /// The caller is responsible for making sure that the return value and stack stays correct
+    /// for the hooked function, as nothing is forwarded to the original.
+    pub fn install_detour_noorig(from_ptr: i64, rune_function: Function, opt_param: Option<Value>) {
+        Self::install_detour_on_free(from_ptr, None, true, rune_function, opt_param);
+    }
+
+    /// Same as `install_detour_auto`, but names the detour so that it can later be dropped with
+    /// `drop_rdetour_named`.
+    pub fn install_detour_named(
+        from_ptr: i64,
+        name: String,
+        rune_function: Function,
+        opt_param: Option<Value>,
+    ) {
+        let Some(rune_detours) = RUNE_DETOURS.try_read() else {
+            log!("[ERROR] rune_detours is locked, cannot access RDetours!");
+            return;
+        };
+
+        if rune_detours.iter().any(|rdetour| {
+            rdetour
+                .try_borrow()
+                .is_ok_and(|rdetour| rdetour.name.as_ref() == Some(&name))
+        }) {
+            log!("[ERROR] An RDetour named \"", name, "\" already exists!");
+            return;
+        }
+
+        drop(rune_detours);
+        Self::install_detour_on_free(from_ptr, Some(name), false, rune_function, opt_param);
+    }
+
+    /// Finds the first-available `RDetour` and installs it on `from_ptr`.
+    fn install_detour_on_free(
+        from_ptr: i64,
+        name: Option<String>,
+        skip_original: bool,
+        rune_function: Function,
+        opt_param: Option<Value>,
+    ) {
         let rune_function = rune_function.into_sync().into_result();
         if let Err(error) = rune_function {
             log!(
@@ -167,7 +220,7 @@ impl RDetour {
             .dynamic_expect(zencstr!(
                 "The found RDetour is locked and cannot be modified"
             ))
-            .install_detour(from_ptr, rune_function, opt_param);
+            .install_detour(from_ptr, name, skip_original, rune_function, opt_param);
     }
 
     /// Finds the first-available `RDetour` and returns it.
@@ -195,6 +248,8 @@ impl RDetour {
     fn install_detour(
         &mut self,
         from_ptr: i64,
+        name: Option<String>,
+        skip_original: bool,
         rune_function: SyncFunction,
         opt_param: Option<Value>,
     ) {
@@ -211,6 +266,8 @@ impl RDetour {
         self.rune_function = Some(rune_function);
         self.from_ptr = Some(from_ptr);
         self.opt_param = opt_param.map(ValueWrapper);
+        self.name = name;
+        self.skip_original = skip_original;
 
         unsafe {
             let hook = Self::create_hook(from_ptr as *const (), to_ptr);
@@ -263,12 +320,17 @@ impl RDetour {
             return 0;
         };
 
-        let Some(detour) = rdetour.get_raw_detour() else {
-            log!("[ERROR] Missing RawDetour for ID ", detour_id, "!");
-            return 0;
+        let original = if rdetour.skip_original {
+            std::ptr::null()
+        } else {
+            let Some(detour) = rdetour.get_raw_detour() else {
+                log!("[ERROR] Missing RawDetour for ID ", detour_id, "!");
+                return 0;
+            };
+
+            detour.trampoline() as *const ()
         };
 
-        let original = detour.trampoline() as *const ();
         let call_res = rune_function
             .call::<(i64, Vec<i64>, Option<&Value>), i64>((
                 original as _,
@@ -344,10 +406,70 @@ impl RDetour {
             return false;
         };
 
-        let Some(detour) = rdetour.detour.take() else {
+        rdetour.release()
+    }
+
+    /// Drops the detour named `name`, if there's any.
+    pub fn drop_rdetour_named(name: &str) -> bool {
+        let Some(rune_detours) = RUNE_DETOURS.try_read() else {
+            log!("[ERROR] rune_detours is locked, cannot access RDetours!");
+            return false;
+        };
+
+        let Some(rdetour) = rune_detours.iter().find(|rdetour| {
+            rdetour
+                .try_borrow()
+                .dynamic_expect(zencstr!(
+                    "RDetour named \"",
+                    name,
+                    "\" is locked and cannot be modified!"
+                ))
+                .name
+                .as_deref()
+                == Some(name)
+        }) else {
+            log!("[ERROR] No RDetour named \"", name, "\" exists!");
+            return false;
+        };
+
+        let Ok(mut rdetour) = rdetour.try_borrow_mut() else {
+            log!(
+                "[ERROR] RDetour named \"",
+                name,
+                "\" is locked and cannot be modified!"
+            );
+            return false;
+        };
+
+        rdetour.release()
+    }
+
+    /// Drops all acquired detours.
+    pub fn drop_all_rdetours() {
+        let Some(rune_detours) = RUNE_DETOURS.try_read() else {
+            log!("[ERROR] rune_detours is locked, cannot access RDetours!");
+            return;
+        };
+
+        for rdetour in rune_detours.iter() {
+            let Ok(mut rdetour) = rdetour.try_borrow_mut() else {
+                log!("[ERROR] RDetour is locked and cannot be dropped!");
+                continue;
+            };
+
+            if rdetour.is_detour_acquired() {
+                rdetour.release();
+            }
+        }
+    }
+
+    /// Disables the detour and frees it, so that it can be acquired again.
+    fn release(&mut self) -> bool {
+        let address = self.get_from_address().unwrap_or_default() as *const i64;
+        let Some(detour) = self.detour.take() else {
             log!(
                 "[ERROR] Couldn't obtain RawDetour from ID ",
-                rdetour.get_detour_id(),
+                self.get_detour_id(),
                 ", address ",
                 format!("{address:?}"),
                 "!"
@@ -360,7 +482,7 @@ impl RDetour {
         {
             log!(
                 "[ERROR] Failed disabling RDetour at ID ",
-                rdetour.get_detour_id(),
+                self.get_detour_id(),
                 ", address ",
                 format!("{address:?}"),
                 ", error: ",
@@ -369,12 +491,57 @@ impl RDetour {
             return false;
         }
 
-        rdetour.from_ptr = None;
-        drop(rdetour.rune_function.take());
+        self.from_ptr = None;
+        self.name = None;
+        self.skip_original = false;
+        drop(self.rune_function.take());
         drop(detour);
         true
     }
 
+    /// Returns the amount of `RDetour`s that are free to be acquired.
+    pub fn get_free_detour_count() -> i64 {
+        let Some(rune_detours) = RUNE_DETOURS.try_read() else {
+            log!("[ERROR] rune_detours is locked, cannot count free RDetours!");
+            return 0;
+        };
+
+        rune_detours
+            .iter()
+            .filter(|rdetour| {
+                rdetour
+                    .try_borrow()
+                    .is_ok_and(|rdetour| !rdetour.is_detour_acquired())
+            })
+            .count() as i64
+    }
+
+    /// Returns `Some(true)` if there's an installed `RDetour` at `address`.
+    /// Returns `None` if the installed `RDetour`s couldn't be accessed.
+    pub fn is_address_hooked(address: i64) -> Option<bool> {
+        let Some(rune_detours) = RUNE_DETOURS.try_read() else {
+            log!("[ERROR] rune_detours is locked, cannot access RDetours!");
+            return None;
+        };
+
+        for rdetour in rune_detours.iter() {
+            let Ok(rdetour) = rdetour.try_borrow() else {
+                log!(
+                    "[ERROR] An RDetour is locked, cannot check if ",
+                    format!("{:?}", address as *const i64),
+                    " is hooked!"
+                );
+                return None;
+            };
+
+            if rdetour.get_from_address().unwrap_or_default() == address {
+                return Some(true);
+            }
+        }
+
+        Some(false)
+    }
+
     /// Returns `self.from_ptr`.
     const fn get_from_address(&self) -> &Option<i64> {
         &self.from_ptr
@@ -402,18 +569,37 @@ impl RDetour {
     }
 }
 
-generate_detour_holder!(detour_holder_00, 0);
-generate_detour_holder!(detour_holder_01, 1);
-generate_detour_holder!(detour_holder_02, 2);
-generate_detour_holder!(detour_holder_03, 3);
-generate_detour_holder!(detour_holder_04, 4);
-generate_detour_holder!(detour_holder_05, 5);
-generate_detour_holder!(detour_holder_06, 6);
-generate_detour_holder!(detour_holder_07, 7);
-generate_detour_holder!(detour_holder_08, 8);
-generate_detour_holder!(detour_holder_09, 9);
-generate_detour_holder!(detour_holder_10, 10);
-generate_detour_holder!(detour_holder_11, 11);
-generate_detour_holder!(detour_holder_12, 12);
-generate_detour_holder!(detour_holder_13, 13);
-generate_detour_holder!(detour_holder_14, 14);
+generate_detour_holders!(
+    detour_holder_00 => 0,
+    detour_holder_01 => 1,
+    detour_holder_02 => 2,
+    detour_holder_03 => 3,
+    detour_holder_04 => 4,
+    detour_holder_05 => 5,
+    detour_holder_06 => 6,
+    detour_holder_07 => 7,
+    detour_holder_08 => 8,
+    detour_holder_09 => 9,
+    detour_holder_10 => 10,
+    detour_holder_11 => 11,
+    detour_holder_12 => 12,
+    detour_holder_13 => 13,
+    detour_holder_14 => 14,
+    detour_holder_15 => 15,
+    detour_holder_16 => 16,
+    detour_holder_17 => 17,
+    detour_holder_18 => 18,
+    detour_holder_19 => 19,
+    detour_holder_20 => 20,
+    detour_holder_21 => 21,
+    detour_holder_22 => 22,
+    detour_holder_23 => 23,
+    detour_holder_24 => 24,
+    detour_holder_25 => 25,
+    detour_holder_26 => 26,
+    detour_holder_27 => 27,
+    detour_holder_28 => 28,
+    detour_holder_29 => 29,
+    detour_holder_30 => 30,
+    detour_holder_31 => 31,
+);
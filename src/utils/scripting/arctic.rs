@@ -20,10 +20,20 @@ use std::{
 };
 use windows::Win32::System::Threading::GetCurrentProcess;
 
+/// Version of the `DNXFunctions` layout, bumped whenever fields are added, removed or reordered.
+/// Plugins export it through `arctic_abi_version`, and aren't loaded if it doesn't match.
+pub const ARCTIC_ABI_VERSION: u32 = 1;
+
 /// A structure that contains a set of functions from dynamic.
+/// Any change to the layout must bump `ARCTIC_ABI_VERSION`, as plugins are built against it.
+/// `repr(C)` keeps the field order stable, as the Rust layout may be reordered by the compiler.
+#[repr(C)]
 #[allow(dead_code)]
 #[allow(clippy::type_complexity)]
 pub struct DNXFunctions {
+    /// Version of this structure's layout, always `ARCTIC_ABI_VERSION`.
+    abi_version: u32,
+
     /// `dynamic::log(message)` function. Logs both to the side-messages, and to `stdout`.
     dynamic_log: fn(&str),
 
@@ -84,6 +94,14 @@ pub struct DNXFunctions {
 
     /// Gets all of the serials from the config.
     config_get_serials: Box<dyn Fn() -> Arc<Vec<String>> + Send + Sync>,
+
+    /// `Memory::write_raw(address, bytes)` function. Writes `bytes` to `address` without any
+    /// validation.
+    memory_write: fn(i64, Vec<u8>),
+
+    /// `Memory::read_raw(address, len)` function. Reads `len` bytes from `address`, returning an
+    /// empty vector if the address is invalid.
+    memory_read_bytes: fn(i64, usize) -> Vec<u8>,
 }
 
 /// Arctic is a plugin system for dynamic which is capable of loading user-created DLLs
@@ -128,6 +146,7 @@ impl Arctic {
                 drop(base_core_reader);
 
                 let funcs = DNXFunctions {
+                    abi_version: ARCTIC_ABI_VERSION,
                     dynamic_log: |data| {
                         log!(data);
                     },
@@ -193,6 +212,8 @@ impl Arctic {
                     }),
                     config_get_path: Box::new(|| config.get_path()),
                     config_get_serials: Box::new(move || Arc::clone(&serials_config_get_serials)),
+                    memory_write: SystemModules::write_raw,
+                    memory_read_bytes: SystemModules::read_raw,
                 };
 
                 // Create the OnceLock instance and assign it before returning.
@@ -209,6 +230,13 @@ impl Arctic {
 
     /// Injects an Arctic DLL and calls its `arctic_gateway` function.
     pub fn inject_plugin(&self, dll_name: String) -> bool {
+        self.inject_plugin_with_arg(dll_name, None)
+    }
+
+    /// Injects an Arctic DLL and calls its gateway function.
+    /// If `arg` is `Some` and the DLL exports `arctic_gateway_ex`, `arg` is passed to it.
+    /// Otherwise the plain `arctic_gateway` function is called, so older plugins still load.
+    pub fn inject_plugin_with_arg(&self, dll_name: String, arg: Option<String>) -> bool {
         let config_path = self.base_core.read().get_config().get_path();
         let mut dll_path = String::with_capacity(config_path.len() + dll_name.len());
         dll_path.push_str(config_path);
@@ -233,6 +261,44 @@ impl Arctic {
             return false;
         }
 
+        let ex_address = arg
+            .is_some()
+            .then(|| WinUtils::get_module_symbol_address(&dll_name, c"arctic_gateway_ex"))
+            .flatten();
+
+        let payload = payload.dynamic_expect(zencstr!("Failed getting payload"));
+        if !Self::is_abi_compatible(&dll_name) {
+            Self::eject_payload(
+                unsafe { OwnedProcess::from_raw_handle(GetCurrentProcess().0 as _) },
+                payload,
+                self.get_injected_dlls(),
+            );
+            return false;
+        }
+
+        // Save the DLL and its payload so we remember it.
+        self.injected_dlls.insert(payload, dll_name.to_owned());
+
+        let process = unsafe { OwnedProcess::from_raw_handle(GetCurrentProcess().0 as _) };
+        let functions = Arc::clone(
+            self.cached_functions
+                .get()
+                .unwrap_or_crash(zencstr!("[ERROR] No cached functions!")),
+        );
+
+        // Handle function calls from the library.
+        if let (Some(address), Some(arg)) = (ex_address, arg) {
+            let func: fn(
+                OwnedProcess,
+                ProcessModule<BorrowedProcess<'static>>,
+                Arc<DNXFunctions>,
+                String,
+            ) = unsafe { std::mem::transmute(address as *const ()) };
+
+            func(process, payload, functions, arg);
+            return true;
+        }
+
         let address = WinUtils::get_module_symbol_address(&dll_name, c"arctic_gateway")
             .unwrap_or_crash(zencstr!(
                 "[ERROR] Can't get the address to arctic_gateway, badly-written plugin!"
@@ -241,20 +307,37 @@ impl Arctic {
         let func: fn(OwnedProcess, ProcessModule<BorrowedProcess<'static>>, Arc<DNXFunctions>) =
             unsafe { std::mem::transmute(address) };
 
-        // Save the DLL and its payload so we remember it.
-        let payload = payload.dynamic_expect(zencstr!("Failed getting payload"));
-        self.injected_dlls.insert(payload, dll_name);
+        func(process, payload, functions);
+        true
+    }
 
-        // Handle function calls from the library.
-        func(
-            unsafe { OwnedProcess::from_raw_handle(GetCurrentProcess().0 as _) },
-            payload,
-            Arc::clone(
-                self.cached_functions
-                    .get()
-                    .unwrap_or_crash(zencstr!("[ERROR] No cached functions!")),
-            ),
-        );
+    /// Checks if the plugin was built against the current `DNXFunctions` layout, by calling its
+    /// exported `arctic_abi_version` function.
+    fn is_abi_compatible(dll_name: &str) -> bool {
+        let Some(address) = WinUtils::get_module_symbol_address(dll_name, c"arctic_abi_version")
+        else {
+            log!(
+                "[ERROR] The plugin \"",
+                dll_name,
+                "\" doesn't export arctic_abi_version, rebuild it against the latest Arctic version!"
+            );
+            return false;
+        };
+
+        let func: extern "C" fn() -> u32 = unsafe { std::mem::transmute(address as *const ()) };
+        let version = func();
+        if version != ARCTIC_ABI_VERSION {
+            log!(
+                "[ERROR] The plugin \"",
+                dll_name,
+                "\" was built for ABI version ",
+                version,
+                ", but dynamic uses version ",
+                ARCTIC_ABI_VERSION,
+                "!"
+            );
+            return false;
+        }
 
         true
     }
@@ -265,7 +348,7 @@ impl Arctic {
         payload: ProcessModule<BorrowedProcess<'static>>,
         injected_dlls: Arc<DashMap<ProcessModule<BorrowedProcess<'static>>, String>>,
     ) {
-        std::thread::spawn(move || {
+        WinUtils::spawn_worker(move || {
             // Remove the payload from the saved DLLs list.
             if let Some(removed) = injected_dlls.remove(&payload).take() {
                 drop(removed);
@@ -282,6 +365,28 @@ impl Arctic {
         });
     }
 
+    /// Ejects an injected plugin by its DLL name.
+    /// Returns `false` and logs an error if no plugin with that name is injected.
+    pub fn eject_plugin(&self, dll_name: &str) -> bool {
+        let Some(payload) = self
+            .injected_dlls
+            .iter()
+            .find(|entry| entry.value() == dll_name)
+            .map(|entry| *entry.key())
+        else {
+            log!("[ERROR] No plugin named \"", dll_name, "\" is injected!");
+            return false;
+        };
+
+        Self::eject_payload(
+            unsafe { OwnedProcess::from_raw_handle(GetCurrentProcess().0 as _) },
+            payload,
+            self.get_injected_dlls(),
+        );
+
+        true
+    }
+
     /// Gets the injected DLLs.
     pub fn get_injected_dlls(
         &self,
@@ -289,6 +394,18 @@ impl Arctic {
         Arc::clone(&self.injected_dlls)
     }
 
+    /// Returns the names of all currently injected plugins, sorted alphabetically.
+    pub fn list_active_plugins(&self) -> Vec<String> {
+        let mut plugins: Vec<String> = self
+            .injected_dlls
+            .iter()
+            .map(|entry| entry.value().to_owned())
+            .collect();
+
+        plugins.sort();
+        plugins
+    }
+
     /// Checks if a gateway/plugin is currently active.
     pub fn is_gateway_active(&self, identifier: &str) -> bool {
         self.get_injected_dlls()
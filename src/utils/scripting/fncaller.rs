@@ -2,24 +2,301 @@ use super::script_core::ScriptCore;
 use crate::utils::extensions::OptionExt;
 use rune::Value;
 
+/// Generates a function which calls `fn_ptr` using the `$abi` calling convention, with the
+/// signature picked from the amount of parameters.
+/// Only needed on 32-bit, as all calling conventions are the same on 64-bit.
+#[cfg(target_arch = "x86")]
+macro_rules! generate_convention_caller {
+    ($name:ident, $abi:literal) => {
+        fn $name<R>(fn_ptr: i64, params: &[i64]) -> R {
+            use std::mem::transmute;
+            type P = *const i64;
+
+            let fn_ptr = fn_ptr as P;
+            unsafe {
+                match params.len() {
+                    0 => transmute::<P, extern $abi fn() -> R>(fn_ptr)(),
+                    1 => transmute::<P, extern $abi fn(P) -> R>(fn_ptr)(params[0] as _),
+                    2 => transmute::<P, extern $abi fn(P, P) -> R>(fn_ptr)(
+                        params[0] as _,
+                        params[1] as _,
+                    ),
+                    3 => transmute::<P, extern $abi fn(P, P, P) -> R>(fn_ptr)(
+                        params[0] as _,
+                        params[1] as _,
+                        params[2] as _,
+                    ),
+                    4 => transmute::<P, extern $abi fn(P, P, P, P) -> R>(fn_ptr)(
+                        params[0] as _,
+                        params[1] as _,
+                        params[2] as _,
+                        params[3] as _,
+                    ),
+                    5 => transmute::<P, extern $abi fn(P, P, P, P, P) -> R>(fn_ptr)(
+                        params[0] as _,
+                        params[1] as _,
+                        params[2] as _,
+                        params[3] as _,
+                        params[4] as _,
+                    ),
+                    6 => transmute::<P, extern $abi fn(P, P, P, P, P, P) -> R>(fn_ptr)(
+                        params[0] as _,
+                        params[1] as _,
+                        params[2] as _,
+                        params[3] as _,
+                        params[4] as _,
+                        params[5] as _,
+                    ),
+                    7 => transmute::<P, extern $abi fn(P, P, P, P, P, P, P) -> R>(fn_ptr)(
+                        params[0] as _,
+                        params[1] as _,
+                        params[2] as _,
+                        params[3] as _,
+                        params[4] as _,
+                        params[5] as _,
+                        params[6] as _,
+                    ),
+                    8 => transmute::<P, extern $abi fn(P, P, P, P, P, P, P, P) -> R>(fn_ptr)(
+                        params[0] as _,
+                        params[1] as _,
+                        params[2] as _,
+                        params[3] as _,
+                        params[4] as _,
+                        params[5] as _,
+                        params[6] as _,
+                        params[7] as _,
+                    ),
+                    9 => transmute::<P, extern $abi fn(P, P, P, P, P, P, P, P, P) -> R>(fn_ptr)(
+                        params[0] as _,
+                        params[1] as _,
+                        params[2] as _,
+                        params[3] as _,
+                        params[4] as _,
+                        params[5] as _,
+                        params[6] as _,
+                        params[7] as _,
+                        params[8] as _,
+                    ),
+                    10 => transmute::<P, extern $abi fn(P, P, P, P, P, P, P, P, P, P) -> R>(fn_ptr)(
+                        params[0] as _,
+                        params[1] as _,
+                        params[2] as _,
+                        params[3] as _,
+                        params[4] as _,
+                        params[5] as _,
+                        params[6] as _,
+                        params[7] as _,
+                        params[8] as _,
+                        params[9] as _,
+                    ),
+                    11 => transmute::<P, extern $abi fn(P, P, P, P, P, P, P, P, P, P, P) -> R>(fn_ptr)(
+                        params[0] as _,
+                        params[1] as _,
+                        params[2] as _,
+                        params[3] as _,
+                        params[4] as _,
+                        params[5] as _,
+                        params[6] as _,
+                        params[7] as _,
+                        params[8] as _,
+                        params[9] as _,
+                        params[10] as _,
+                    ),
+                    12 => transmute::<P, extern $abi fn(P, P, P, P, P, P, P, P, P, P, P, P) -> R>(fn_ptr)(
+                        params[0] as _,
+                        params[1] as _,
+                        params[2] as _,
+                        params[3] as _,
+                        params[4] as _,
+                        params[5] as _,
+                        params[6] as _,
+                        params[7] as _,
+                        params[8] as _,
+                        params[9] as _,
+                        params[10] as _,
+                        params[11] as _,
+                    ),
+                    13 => transmute::<P, extern $abi fn(P, P, P, P, P, P, P, P, P, P, P, P, P) -> R>(fn_ptr)(
+                        params[0] as _,
+                        params[1] as _,
+                        params[2] as _,
+                        params[3] as _,
+                        params[4] as _,
+                        params[5] as _,
+                        params[6] as _,
+                        params[7] as _,
+                        params[8] as _,
+                        params[9] as _,
+                        params[10] as _,
+                        params[11] as _,
+                        params[12] as _,
+                    ),
+                    14 => transmute::<P, extern $abi fn(P, P, P, P, P, P, P, P, P, P, P, P, P, P) -> R>(fn_ptr)(
+                        params[0] as _,
+                        params[1] as _,
+                        params[2] as _,
+                        params[3] as _,
+                        params[4] as _,
+                        params[5] as _,
+                        params[6] as _,
+                        params[7] as _,
+                        params[8] as _,
+                        params[9] as _,
+                        params[10] as _,
+                        params[11] as _,
+                        params[12] as _,
+                        params[13] as _,
+                    ),
+                    15 => transmute::<P, extern $abi fn(P, P, P, P, P, P, P, P, P, P, P, P, P, P, P) -> R>(fn_ptr)(
+                        params[0] as _,
+                        params[1] as _,
+                        params[2] as _,
+                        params[3] as _,
+                        params[4] as _,
+                        params[5] as _,
+                        params[6] as _,
+                        params[7] as _,
+                        params[8] as _,
+                        params[9] as _,
+                        params[10] as _,
+                        params[11] as _,
+                        params[12] as _,
+                        params[13] as _,
+                        params[14] as _,
+                    ),
+                    params_len => crash!(
+                        "[ERROR] Parameter count is unchecked. Got ",
+                        params_len,
+                        " parameters, expected 15 or less, closing."
+                    ),
+                }
+            }
+        }
+    };
+}
+
+/// Calls `fn_ptr` with the given `param => type` pairs, reading the return value as an `i64`.
+/// Used by `FNCaller::call_mixed` so that floats can be passed through the XMM registers.
+macro_rules! call_mixed {
+    ($fn_ptr:expr $(, $param:expr => $ty:ty)*) => {
+        unsafe {
+            std::mem::transmute::<*const i64, extern "system" fn($($ty),*) -> *const i64>(
+                $fn_ptr as *const i64,
+            )($($param),*) as i64
+        }
+    };
+}
+
+/// Calling convention used when calling a function through `FNCaller`.
+/// On 64-bit, all calling conventions are the same and the convention is ignored.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum CallingConvention {
+    /// `extern "system"`, aka `stdcall` on 32-bit.
+    System,
+
+    /// `extern "C"`, aka `cdecl` on 32-bit.
+    C,
+
+    /// `extern "fastcall"`.
+    Fastcall,
+}
+
+impl CallingConvention {
+    /// Gets the calling convention from its id.
+    /// 0 = System (stdcall), 1 = C (cdecl), 2 = Fastcall.
+    pub fn from_id(id: i64) -> Option<Self> {
+        match id {
+            0 => Some(Self::System),
+            1 => Some(Self::C),
+            2 => Some(Self::Fastcall),
+            _ => None,
+        }
+    }
+}
+
+/// How a parameter is passed through `FNCaller::call_mixed_typed`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum MixedParamType {
+    /// Picked from the value, floats are passed as `f64` and everything else as a native pointer.
+    Auto,
+
+    /// Native pointer, passed through the integer registers.
+    Pointer,
+
+    /// `float`, passed through the XMM registers.
+    F32,
+
+    /// `double`, passed through the XMM registers.
+    F64,
+}
+
+impl MixedParamType {
+    /// Gets the parameter type from its id.
+    /// 0 = Auto, 1 = Pointer, 2 = F32, 3 = F64.
+    pub fn from_id(id: i64) -> Option<Self> {
+        match id {
+            0 => Some(Self::Auto),
+            1 => Some(Self::Pointer),
+            2 => Some(Self::F32),
+            3 => Some(Self::F64),
+            _ => None,
+        }
+    }
+}
+
 /// Experimental function caller.
 /// No safety is included with this, use at your own risk!
 ///
 /// Design Issue: C-Functions like `void name(longlong, float)` cannot be called properly, as the
 /// `float` parameter is always getting sent an i64 value, with (currently) no safe way of changing
 /// it and make it work with both.
+/// `call_mixed` works around it for functions with up to 4 parameters.
 pub struct FNCaller;
 
 #[allow(clippy::too_many_arguments)]
 impl FNCaller {
     /// Takes the function pointer and the paramaters in form of a `Vec<i64>`.
-    /// If there are more than 10 entries in `params`, this function sends an error message
+    /// If there are more than 15 entries in `params`, this function sends an error message
     /// and returns 0.
     /// If below or equal to 15 entries, the correct `call_xx` function is found and called.
     /// # Design
     /// This could be done via `c_variadic`, but it causes too many undefined behaviors due to
     /// always passing more parameters than needed.
-    pub fn call_auto_raw(fn_ptr: i64, params: Vec<i64>) -> i64 {
+    ///
+    /// `convention` is the id of the calling convention to use, see `CallingConvention::from_id`.
+    pub fn call_auto_raw(fn_ptr: i64, params: Vec<i64>, convention: i64) -> i64 {
+        let Some(convention) = CallingConvention::from_id(convention) else {
+            log!(
+                "[ERROR] Unknown calling convention ",
+                convention,
+                ", expected 0 (System), 1 (C) or 2 (Fastcall), returning 0."
+            );
+            return 0;
+        };
+
+        Self::call_auto_raw_as::<*const i64>(fn_ptr, &params, convention)
+            .map_or(0, |result| result as i64)
+    }
+
+    /// Same as `call_auto_raw`, but reads the return value as a `f32`.
+    pub fn call_auto_raw_f32(fn_ptr: i64, params: Vec<i64>) -> f32 {
+        Self::call_auto_raw_as(fn_ptr, &params, CallingConvention::System).unwrap_or_default()
+    }
+
+    /// Same as `call_auto_raw`, but reads the return value as a `f64`.
+    pub fn call_auto_raw_f64(fn_ptr: i64, params: Vec<i64>) -> f64 {
+        Self::call_auto_raw_as(fn_ptr, &params, CallingConvention::System).unwrap_or_default()
+    }
+
+    /// Finds the correct `call_xx` function for the amount of parameters and calls it, reading
+    /// the return value as `R`.
+    /// Returns `None` if there are more than 15 entries in `params`.
+    #[cfg_attr(not(target_arch = "x86"), allow(unused_variables))]
+    fn call_auto_raw_as<R>(
+        fn_ptr: i64,
+        params: &[i64],
+        convention: CallingConvention,
+    ) -> Option<R> {
         let params_len = params.len();
         if params_len > 15 {
             log!("[ERROR] Max amount of parameters reached, keep it below (or eq. to) 15!");
@@ -28,10 +305,17 @@ impl FNCaller {
                 params_len,
                 " parameters, expected 15 or less, returning 0."
             );
-            return 0;
+            return None;
         }
 
-        match params_len {
+        #[cfg(target_arch = "x86")]
+        match convention {
+            CallingConvention::System => (),
+            CallingConvention::C => return Some(Self::call_c(fn_ptr, params)),
+            CallingConvention::Fastcall => return Some(Self::call_fastcall(fn_ptr, params)),
+        }
+
+        let result = match params_len {
             0 => Self::call(fn_ptr),
             1 => Self::call_00(fn_ptr, params[0]),
             2 => Self::call_01(fn_ptr, params[0], params[1]),
@@ -85,115 +369,314 @@ impl FNCaller {
                 params_len,
                 " parameters, expected 15 or less, closing."
             ),
-        }
+        };
+
+        Some(result)
     }
 
     /// Same as `call_auto_raw`, but takes all values in the vector as a Rune `Value` and turns it
     /// into the native pointer.
     /// This is recommended for when you don't want to get the pointer of each value manually, but
     /// discouraged when you are passing pre-defined pointers into it.
-    pub fn call_auto(fn_ptr: i64, params: Vec<Value>) -> i64 {
+    pub fn call_auto(fn_ptr: i64, params: Vec<Value>, convention: i64) -> i64 {
+        let Some(params) = Self::values_as_ptrs(params) else {
+            return 0;
+        };
+
+        Self::call_auto_raw(fn_ptr, params, convention)
+    }
+
+    /// Same as `call_auto`, but reads the return value as a `f32`.
+    pub fn call_auto_f32(fn_ptr: i64, params: Vec<Value>) -> f32 {
+        let Some(params) = Self::values_as_ptrs(params) else {
+            return 0.0;
+        };
+
+        Self::call_auto_raw_f32(fn_ptr, params)
+    }
+
+    /// Same as `call_auto`, but reads the return value as a `f64`.
+    pub fn call_auto_f64(fn_ptr: i64, params: Vec<Value>) -> f64 {
+        let Some(params) = Self::values_as_ptrs(params) else {
+            return 0.0;
+        };
+
+        Self::call_auto_raw_f64(fn_ptr, params)
+    }
+
+    /// Calls `fn_ptr` with `params`, passing floats as `f64` through the XMM registers and
+    /// everything else as native pointers through the integer registers.
+    /// This works around the design issue described on `FNCaller`.
+    ///
+    /// # Limits
+    /// - At most 4 parameters are supported, as only the first 4 are passed through registers.
+    /// - Parameters are positional, a float at index 1 is passed through `XMM1` and an integer at
+    ///   index 1 through `RDX`. The order has to match the signature of the function.
+    /// - Floats are always passed as `f64`, use `call_mixed_typed` for functions taking a `float`.
+    /// - The return value is always read as an integer.
+    pub fn call_mixed(fn_ptr: i64, params: Vec<Value>) -> i64 {
+        let types = vec![MixedParamType::Auto; params.len()];
+        Self::call_mixed_as(fn_ptr, &params, &types)
+    }
+
+    /// Same as `call_mixed`, but `types` decides how each parameter is passed, see
+    /// `MixedParamType::from_id`.
+    /// `types` has to contain exactly one entry per parameter.
+    pub fn call_mixed_typed(fn_ptr: i64, params: Vec<Value>, types: Vec<i64>) -> i64 {
+        if types.len() != params.len() {
+            log!(
+                "[ERROR] Got ",
+                params.len(),
+                " parameters but ",
+                types.len(),
+                " parameter types, returning 0."
+            );
+            return 0;
+        }
+
+        let mut param_types = Vec::with_capacity(types.len());
+        for id in types {
+            let Some(param_type) = MixedParamType::from_id(id) else {
+                log!(
+                    "[ERROR] Unknown parameter type ",
+                    id,
+                    ", expected 0 (Auto), 1 (Pointer), 2 (F32) or 3 (F64), returning 0."
+                );
+                return 0;
+            };
+
+            param_types.push(param_type);
+        }
+
+        Self::call_mixed_as(fn_ptr, &params, &param_types)
+    }
+
+    /// Calls `fn_ptr` with `params`, passing each parameter as described by its entry in
+    /// `types`.
+    fn call_mixed_as(fn_ptr: i64, params: &[Value], types: &[MixedParamType]) -> i64 {
         let params_len = params.len();
-        if params_len > 15 {
-            log!("[ERROR] Max amount of parameters reached, keep it below (or eq. to) 15!");
+        if params_len > 4 {
+            log!("[ERROR] Max amount of mixed parameters reached, keep it below (or eq. to) 4!");
             log!(
                 "[ERROR] Got ",
                 params_len,
-                " parameters, expected 15 or less, returning 0."
+                " parameters, expected 4 or less, returning 0."
             );
             return 0;
         }
 
-        let params: Vec<i64> = params
-            .iter()
-            .map(|value| {
-                ScriptCore::value_as_ptr(value).unwrap_or_crash(zencstr!(
-                    "[ERROR] Couldn't get the pointer of \"",
+        let mut ints = [0i64; 4];
+        let mut floats = [0f64; 4];
+        let mut float_mask = 0u8;
+        for (index, (value, param_type)) in params.iter().zip(types).enumerate() {
+            let float = match param_type {
+                MixedParamType::Auto | MixedParamType::F64 => value.as_float().into_result().ok(),
+                MixedParamType::F32 => value.as_float().into_result().ok().map(|float| {
+                    // A function taking a `float` only reads the low 32 bits of the XMM register,
+                    // so the `f32` bits are placed there and passed along as an `f64`.
+                    f64::from_bits(u64::from((float as f32).to_bits()))
+                }),
+                MixedParamType::Pointer => None,
+            };
+
+            if let Some(float) = float {
+                floats[index] = float;
+                float_mask |= 1 << index;
+                continue;
+            }
+
+            if matches!(param_type, MixedParamType::F32 | MixedParamType::F64) {
+                log!(
+                    "[ERROR] Parameter at index ",
+                    index,
+                    " was marked as a float, but \"",
                     format!("{value:?}"),
-                    "\"!"
-                )) as i64
-            })
-            .collect();
+                    "\" isn't one, returning 0."
+                );
+                return 0;
+            }
 
-        Self::call_auto_raw(fn_ptr, params)
+            ints[index] = ScriptCore::value_as_ptr(value).unwrap_or_crash(zencstr!(
+                "[ERROR] Couldn't get the pointer of \"",
+                format!("{value:?}"),
+                "\"!"
+            )) as i64;
+        }
+
+        // Each bit in `float_mask` decides whether the parameter at that index is a float.
+        match (params_len, float_mask) {
+            (0, _) => call_mixed!(fn_ptr),
+            (1, 0b0) => call_mixed!(fn_ptr, ints[0] => i64),
+            (1, 0b1) => call_mixed!(fn_ptr, floats[0] => f64),
+            (2, 0b00) => call_mixed!(fn_ptr, ints[0] => i64, ints[1] => i64),
+            (2, 0b01) => call_mixed!(fn_ptr, floats[0] => f64, ints[1] => i64),
+            (2, 0b10) => call_mixed!(fn_ptr, ints[0] => i64, floats[1] => f64),
+            (2, 0b11) => call_mixed!(fn_ptr, floats[0] => f64, floats[1] => f64),
+            (3, 0b000) => call_mixed!(fn_ptr, ints[0] => i64, ints[1] => i64, ints[2] => i64),
+            (3, 0b001) => call_mixed!(fn_ptr, floats[0] => f64, ints[1] => i64, ints[2] => i64),
+            (3, 0b010) => call_mixed!(fn_ptr, ints[0] => i64, floats[1] => f64, ints[2] => i64),
+            (3, 0b011) => call_mixed!(fn_ptr, floats[0] => f64, floats[1] => f64, ints[2] => i64),
+            (3, 0b100) => call_mixed!(fn_ptr, ints[0] => i64, ints[1] => i64, floats[2] => f64),
+            (3, 0b101) => call_mixed!(fn_ptr, floats[0] => f64, ints[1] => i64, floats[2] => f64),
+            (3, 0b110) => call_mixed!(fn_ptr, ints[0] => i64, floats[1] => f64, floats[2] => f64),
+            (3, 0b111) => call_mixed!(fn_ptr, floats[0] => f64, floats[1] => f64, floats[2] => f64),
+            (4, 0b0000) => {
+                call_mixed!(fn_ptr, ints[0] => i64, ints[1] => i64, ints[2] => i64, ints[3] => i64)
+            }
+            (4, 0b0001) => {
+                call_mixed!(fn_ptr, floats[0] => f64, ints[1] => i64, ints[2] => i64, ints[3] => i64)
+            }
+            (4, 0b0010) => {
+                call_mixed!(fn_ptr, ints[0] => i64, floats[1] => f64, ints[2] => i64, ints[3] => i64)
+            }
+            (4, 0b0011) => {
+                call_mixed!(fn_ptr, floats[0] => f64, floats[1] => f64, ints[2] => i64, ints[3] => i64)
+            }
+            (4, 0b0100) => {
+                call_mixed!(fn_ptr, ints[0] => i64, ints[1] => i64, floats[2] => f64, ints[3] => i64)
+            }
+            (4, 0b0101) => {
+                call_mixed!(fn_ptr, floats[0] => f64, ints[1] => i64, floats[2] => f64, ints[3] => i64)
+            }
+            (4, 0b0110) => {
+                call_mixed!(fn_ptr, ints[0] => i64, floats[1] => f64, floats[2] => f64, ints[3] => i64)
+            }
+            (4, 0b0111) => {
+                call_mixed!(fn_ptr, floats[0] => f64, floats[1] => f64, floats[2] => f64, ints[3] => i64)
+            }
+            (4, 0b1000) => {
+                call_mixed!(fn_ptr, ints[0] => i64, ints[1] => i64, ints[2] => i64, floats[3] => f64)
+            }
+            (4, 0b1001) => {
+                call_mixed!(fn_ptr, floats[0] => f64, ints[1] => i64, ints[2] => i64, floats[3] => f64)
+            }
+            (4, 0b1010) => {
+                call_mixed!(fn_ptr, ints[0] => i64, floats[1] => f64, ints[2] => i64, floats[3] => f64)
+            }
+            (4, 0b1011) => {
+                call_mixed!(fn_ptr, floats[0] => f64, floats[1] => f64, ints[2] => i64, floats[3] => f64)
+            }
+            (4, 0b1100) => {
+                call_mixed!(fn_ptr, ints[0] => i64, ints[1] => i64, floats[2] => f64, floats[3] => f64)
+            }
+            (4, 0b1101) => {
+                call_mixed!(fn_ptr, floats[0] => f64, ints[1] => i64, floats[2] => f64, floats[3] => f64)
+            }
+            (4, 0b1110) => {
+                call_mixed!(fn_ptr, ints[0] => i64, floats[1] => f64, floats[2] => f64, floats[3] => f64)
+            }
+            (4, 0b1111) => {
+                call_mixed!(fn_ptr, floats[0] => f64, floats[1] => f64, floats[2] => f64, floats[3] => f64)
+            }
+            (params_len, _) => crash!(
+                "[ERROR] Parameter count is unchecked. Got ",
+                params_len,
+                " parameters, expected 4 or less, closing."
+            ),
+        }
     }
 
-    pub fn call(fn_ptr: i64) -> i64 {
-        let fn_ptr = fn_ptr as *const i64;
-        unsafe {
-            std::mem::transmute::<*const i64, extern "system" fn() -> *const i64>(fn_ptr)() as _
+    /// Turns all values in `params` into native pointers.
+    /// Returns `None` if there are more than 15 entries in `params`.
+    fn values_as_ptrs(params: Vec<Value>) -> Option<Vec<i64>> {
+        let params_len = params.len();
+        if params_len > 15 {
+            log!("[ERROR] Max amount of parameters reached, keep it below (or eq. to) 15!");
+            log!(
+                "[ERROR] Got ",
+                params_len,
+                " parameters, expected 15 or less, returning 0."
+            );
+            return None;
         }
+
+        Some(
+            params
+                .iter()
+                .map(|value| {
+                    ScriptCore::value_as_ptr(value).unwrap_or_crash(zencstr!(
+                        "[ERROR] Couldn't get the pointer of \"",
+                        format!("{value:?}"),
+                        "\"!"
+                    )) as i64
+                })
+                .collect(),
+        )
     }
 
-    pub fn call_00(fn_ptr: i64, param_0: i64) -> i64 {
+    #[cfg(target_arch = "x86")]
+    generate_convention_caller!(call_c, "C");
+
+    #[cfg(target_arch = "x86")]
+    generate_convention_caller!(call_fastcall, "fastcall");
+
+    pub fn call<R>(fn_ptr: i64) -> R {
+        let fn_ptr = fn_ptr as *const i64;
+        unsafe { std::mem::transmute::<*const i64, extern "system" fn() -> R>(fn_ptr)() }
+    }
+
+    pub fn call_00<R>(fn_ptr: i64, param_0: i64) -> R {
         let fn_ptr = fn_ptr as *const i64;
         unsafe {
-            std::mem::transmute::<*const i64, extern "system" fn(*const i64) -> *const i64>(fn_ptr)(
+            std::mem::transmute::<*const i64, extern "system" fn(*const i64) -> R>(fn_ptr)(
                 param_0 as _,
-            ) as _
+            )
         }
     }
 
-    pub fn call_01(fn_ptr: i64, param_0: i64, param_1: i64) -> i64 {
+    pub fn call_01<R>(fn_ptr: i64, param_0: i64, param_1: i64) -> R {
         let fn_ptr = fn_ptr as *const i64;
         unsafe {
-            std::mem::transmute::<
-                *const i64,
-                extern "system" fn(*const i64, *const i64) -> *const i64,
-            >(fn_ptr)(param_0 as _, param_1 as _) as _
+            std::mem::transmute::<*const i64, extern "system" fn(*const i64, *const i64) -> R>(
+                fn_ptr,
+            )(param_0 as _, param_1 as _)
         }
     }
 
-    pub fn call_02(fn_ptr: i64, param_0: i64, param_1: i64, param_2: i64) -> i64 {
+    pub fn call_02<R>(fn_ptr: i64, param_0: i64, param_1: i64, param_2: i64) -> R {
         let fn_ptr = fn_ptr as *const i64;
         unsafe {
             std::mem::transmute::<
                 *const i64,
-                extern "system" fn(*const i64, *const i64, *const i64) -> *const i64,
-            >(fn_ptr)(param_0 as _, param_1 as _, param_2 as _) as _
+                extern "system" fn(*const i64, *const i64, *const i64) -> R,
+            >(fn_ptr)(param_0 as _, param_1 as _, param_2 as _)
         }
     }
 
-    pub fn call_03(fn_ptr: i64, param_0: i64, param_1: i64, param_2: i64, param_3: i64) -> i64 {
+    pub fn call_03<R>(fn_ptr: i64, param_0: i64, param_1: i64, param_2: i64, param_3: i64) -> R {
         let fn_ptr = fn_ptr as *const i64;
         unsafe {
             std::mem::transmute::<
                 *const i64,
-                extern "system" fn(*const i64, *const i64, *const i64, *const i64) -> *const i64,
-            >(fn_ptr)(param_0 as _, param_1 as _, param_2 as _, param_3 as _) as _
+                extern "system" fn(*const i64, *const i64, *const i64, *const i64) -> R,
+            >(fn_ptr)(param_0 as _, param_1 as _, param_2 as _, param_3 as _)
         }
     }
 
-    pub fn call_04(
+    pub fn call_04<R>(
         fn_ptr: i64,
         param_0: i64,
         param_1: i64,
         param_2: i64,
         param_3: i64,
         param_4: i64,
-    ) -> i64 {
+    ) -> R {
         let fn_ptr = fn_ptr as *const i64;
         unsafe {
             std::mem::transmute::<
                 *const i64,
-                extern "system" fn(
-                    *const i64,
-                    *const i64,
-                    *const i64,
-                    *const i64,
-                    *const i64,
-                ) -> *const i64,
+                extern "system" fn(*const i64, *const i64, *const i64, *const i64, *const i64) -> R,
             >(fn_ptr)(
                 param_0 as _,
                 param_1 as _,
                 param_2 as _,
                 param_3 as _,
                 param_4 as _,
-            ) as _
+            )
         }
     }
 
-    pub fn call_05(
+    pub fn call_05<R>(
         fn_ptr: i64,
         param_0: i64,
         param_1: i64,
@@ -201,7 +684,7 @@ impl FNCaller {
         param_3: i64,
         param_4: i64,
         param_5: i64,
-    ) -> i64 {
+    ) -> R {
         let fn_ptr = fn_ptr as *const i64;
         unsafe {
             std::mem::transmute::<
@@ -213,7 +696,7 @@ impl FNCaller {
                     *const i64,
                     *const i64,
                     *const i64,
-                ) -> *const i64,
+                ) -> R,
             >(fn_ptr)(
                 param_0 as _,
                 param_1 as _,
@@ -221,11 +704,11 @@ impl FNCaller {
                 param_3 as _,
                 param_4 as _,
                 param_5 as _,
-            ) as _
+            )
         }
     }
 
-    pub fn call_06(
+    pub fn call_06<R>(
         fn_ptr: i64,
         param_0: i64,
         param_1: i64,
@@ -234,7 +717,7 @@ impl FNCaller {
         param_4: i64,
         param_5: i64,
         param_6: i64,
-    ) -> i64 {
+    ) -> R {
         let fn_ptr = fn_ptr as *const i64;
         unsafe {
             std::mem::transmute::<
@@ -247,7 +730,7 @@ impl FNCaller {
                     *const i64,
                     *const i64,
                     *const i64,
-                ) -> *const i64,
+                ) -> R,
             >(fn_ptr)(
                 param_0 as _,
                 param_1 as _,
@@ -256,11 +739,11 @@ impl FNCaller {
                 param_4 as _,
                 param_5 as _,
                 param_6 as _,
-            ) as _
+            )
         }
     }
 
-    pub fn call_07(
+    pub fn call_07<R>(
         fn_ptr: i64,
         param_0: i64,
         param_1: i64,
@@ -270,7 +753,7 @@ impl FNCaller {
         param_5: i64,
         param_6: i64,
         param_7: i64,
-    ) -> i64 {
+    ) -> R {
         let fn_ptr = fn_ptr as *const i64;
         unsafe {
             std::mem::transmute::<
@@ -284,7 +767,7 @@ impl FNCaller {
                     *const i64,
                     *const i64,
                     *const i64,
-                ) -> *const i64,
+                ) -> R,
             >(fn_ptr)(
                 param_0 as _,
                 param_1 as _,
@@ -294,11 +777,11 @@ impl FNCaller {
                 param_5 as _,
                 param_6 as _,
                 param_7 as _,
-            ) as _
+            )
         }
     }
 
-    pub fn call_08(
+    pub fn call_08<R>(
         fn_ptr: i64,
         param_0: i64,
         param_1: i64,
@@ -309,7 +792,7 @@ impl FNCaller {
         param_6: i64,
         param_7: i64,
         param_8: i64,
-    ) -> i64 {
+    ) -> R {
         let fn_ptr = fn_ptr as *const i64;
         unsafe {
             std::mem::transmute::<
@@ -324,7 +807,7 @@ impl FNCaller {
                     *const i64,
                     *const i64,
                     *const i64,
-                ) -> *const i64,
+                ) -> R,
             >(fn_ptr)(
                 param_0 as _,
                 param_1 as _,
@@ -335,11 +818,11 @@ impl FNCaller {
                 param_6 as _,
                 param_7 as _,
                 param_8 as _,
-            ) as _
+            )
         }
     }
 
-    pub fn call_09(
+    pub fn call_09<R>(
         fn_ptr: i64,
         param_0: i64,
         param_1: i64,
@@ -351,7 +834,7 @@ impl FNCaller {
         param_7: i64,
         param_8: i64,
         param_9: i64,
-    ) -> i64 {
+    ) -> R {
         let fn_ptr = fn_ptr as *const i64;
         unsafe {
             std::mem::transmute::<
@@ -367,7 +850,7 @@ impl FNCaller {
                     *const i64,
                     *const i64,
                     *const i64,
-                ) -> *const i64,
+                ) -> R,
             >(fn_ptr)(
                 param_0 as _,
                 param_1 as _,
@@ -379,11 +862,11 @@ impl FNCaller {
                 param_7 as _,
                 param_8 as _,
                 param_9 as _,
-            ) as _
+            )
         }
     }
 
-    pub fn call_10(
+    pub fn call_10<R>(
         fn_ptr: i64,
         param_0: i64,
         param_1: i64,
@@ -396,7 +879,7 @@ impl FNCaller {
         param_8: i64,
         param_9: i64,
         param_10: i64,
-    ) -> i64 {
+    ) -> R {
         let fn_ptr = fn_ptr as *const i64;
         unsafe {
             std::mem::transmute::<
@@ -413,7 +896,7 @@ impl FNCaller {
                     *const i64,
                     *const i64,
                     *const i64,
-                ) -> *const i64,
+                ) -> R,
             >(fn_ptr)(
                 param_0 as _,
                 param_1 as _,
@@ -426,11 +909,11 @@ impl FNCaller {
                 param_8 as _,
                 param_9 as _,
                 param_10 as _,
-            ) as _
+            )
         }
     }
 
-    pub fn call_11(
+    pub fn call_11<R>(
         fn_ptr: i64,
         param_0: i64,
         param_1: i64,
@@ -444,7 +927,7 @@ impl FNCaller {
         param_9: i64,
         param_10: i64,
         param_11: i64,
-    ) -> i64 {
+    ) -> R {
         let fn_ptr = fn_ptr as *const i64;
         unsafe {
             std::mem::transmute::<
@@ -462,7 +945,7 @@ impl FNCaller {
                     *const i64,
                     *const i64,
                     *const i64,
-                ) -> *const i64,
+                ) -> R,
             >(fn_ptr)(
                 param_0 as _,
                 param_1 as _,
@@ -476,11 +959,11 @@ impl FNCaller {
                 param_9 as _,
                 param_10 as _,
                 param_11 as _,
-            ) as _
+            )
         }
     }
 
-    pub fn call_12(
+    pub fn call_12<R>(
         fn_ptr: i64,
         param_0: i64,
         param_1: i64,
@@ -495,7 +978,7 @@ impl FNCaller {
         param_10: i64,
         param_11: i64,
         param_12: i64,
-    ) -> i64 {
+    ) -> R {
         let fn_ptr = fn_ptr as *const i64;
         unsafe {
             std::mem::transmute::<
@@ -514,7 +997,7 @@ impl FNCaller {
                     *const i64,
                     *const i64,
                     *const i64,
-                ) -> *const i64,
+                ) -> R,
             >(fn_ptr)(
                 param_0 as _,
                 param_1 as _,
@@ -529,11 +1012,11 @@ impl FNCaller {
                 param_10 as _,
                 param_11 as _,
                 param_12 as _,
-            ) as _
+            )
         }
     }
 
-    pub fn call_13(
+    pub fn call_13<R>(
         fn_ptr: i64,
         param_0: i64,
         param_1: i64,
@@ -549,7 +1032,7 @@ impl FNCaller {
         param_11: i64,
         param_12: i64,
         param_13: i64,
-    ) -> i64 {
+    ) -> R {
         let fn_ptr = fn_ptr as *const i64;
         unsafe {
             std::mem::transmute::<
@@ -569,7 +1052,7 @@ impl FNCaller {
                     *const i64,
                     *const i64,
                     *const i64,
-                ) -> *const i64,
+                ) -> R,
             >(fn_ptr)(
                 param_0 as _,
                 param_1 as _,
@@ -585,11 +1068,11 @@ impl FNCaller {
                 param_11 as _,
                 param_12 as _,
                 param_13 as _,
-            ) as _
+            )
         }
     }
 
-    pub fn call_14(
+    pub fn call_14<R>(
         fn_ptr: i64,
         param_0: i64,
         param_1: i64,
@@ -606,7 +1089,7 @@ impl FNCaller {
         param_12: i64,
         param_13: i64,
         param_14: i64,
-    ) -> i64 {
+    ) -> R {
         let fn_ptr = fn_ptr as *const i64;
         unsafe {
             std::mem::transmute::<
@@ -627,7 +1110,7 @@ impl FNCaller {
                     *const i64,
                     *const i64,
                     *const i64,
-                ) -> *const i64,
+                ) -> R,
             >(fn_ptr)(
                 param_0 as _,
                 param_1 as _,
@@ -644,7 +1127,53 @@ impl FNCaller {
                 param_12 as _,
                 param_13 as _,
                 param_14 as _,
-            ) as _
+            )
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    extern "system" fn scale_f32(value: f32) -> i64 {
+        (value * 10.0) as i64
+    }
+
+    extern "system" fn scale_f64(value: f64) -> i64 {
+        (value * 10.0) as i64
+    }
+
+    extern "system" fn add_f32_f64(int: i64, single: f32, double: f64) -> i64 {
+        int + (single * 10.0) as i64 + (double * 100.0) as i64
+    }
+
+    #[test]
+    fn passes_f32_parameters() {
+        let result = FNCaller::call_mixed_typed(scale_f32 as i64, vec![Value::Float(2.5)], vec![2]);
+        assert_eq!(result, 25);
+    }
+
+    #[test]
+    fn passes_f64_parameters() {
+        let params = vec![Value::Float(2.5)];
+        assert_eq!(FNCaller::call_mixed(scale_f64 as i64, params.clone()), 25);
+        assert_eq!(
+            FNCaller::call_mixed_typed(scale_f64 as i64, params, vec![3]),
+            25
+        );
+    }
+
+    #[test]
+    fn passes_both_widths_next_to_pointers() {
+        let params = vec![Value::Integer(1000), Value::Float(2.5), Value::Float(0.5)];
+        let result = FNCaller::call_mixed_typed(add_f32_f64 as i64, params, vec![1, 2, 3]);
+        assert_eq!(result, 1075);
+    }
+
+    #[test]
+    fn rejects_mismatched_parameter_types() {
+        let result = FNCaller::call_mixed_typed(scale_f32 as i64, vec![Value::Float(2.5)], vec![]);
+        assert_eq!(result, 0);
+    }
+}
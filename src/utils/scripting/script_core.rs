@@ -1,20 +1,32 @@
 use super::script_modules::SystemModules;
 use crate::{
+    globals::{DELTA_TIME, IS_UNLOADING},
     mod_cores::base_core::BaseCore,
     utils::{
         crosscom::CrossCom,
         extensions::{OptionExt, StringExtensions},
         scripting::{arctic::Arctic, script_modules::UIModules},
+        stringutils::StringUtils,
     },
+    winutils::WinUtils,
 };
-use dashmap::DashMap;
+use dashmap::{mapref::entry::Entry, DashMap};
 use parking_lot::{Mutex, RwLock};
 use rune::{
-    termcolor::{ColorChoice, StandardStream},
+    termcolor::{Buffer, ColorChoice, StandardStream, WriteColor},
     *,
 };
 use runtime::SyncFunction;
-use std::{error::Error, ffi::CString, sync::Arc};
+use std::{
+    error::Error,
+    ffi::CString,
+    io::Write,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
 use zstring::ZString;
 
 /// Wrapper around `Value` to force it to be "thread-safe".
@@ -60,24 +72,53 @@ impl MutexValue {
 }
 
 /// Information about a frame update callback.
+#[derive(Clone)]
 pub struct FrameUpdateCallback {
     /// Callback function.
-    callback: SyncFunction,
+    callback: Arc<SyncFunction>,
 
     /// Optional parameter to pass into `callback`.
     opt_param: Option<Value>,
+
+    /// If `true`, the current delta time is passed into `callback` as the last parameter.
+    pass_delta_time: bool,
+
+    /// If set, `callback` is only called for this window.
+    window: Option<String>,
+
+    /// Execution priority, callbacks with a lower priority are called first.
+    /// Callbacks with the same priority are called in the order of their identifiers.
+    priority: i64,
 }
 
 impl FrameUpdateCallback {
     /// Builds a new instance of `FrameUpdateCallback`.
-    pub fn new(callback: SyncFunction, opt_param: Option<Value>) -> Self {
+    pub fn new(
+        callback: SyncFunction,
+        opt_param: Option<Value>,
+        pass_delta_time: bool,
+        window: Option<String>,
+        priority: i64,
+    ) -> Self {
         Self {
-            callback,
+            callback: Arc::new(callback),
             opt_param,
+            pass_delta_time,
+            window,
+            priority,
         }
     }
 }
 
+/// Information about a hotkey bound to a script file.
+pub struct HotkeyBinding {
+    /// Relative path to the script that should be executed.
+    relative_path: String,
+
+    /// Was the key held down on the last frame?
+    was_down: bool,
+}
+
 /// Structure that implements Send and Sync so that the `Vm` inside of it can be used for
 /// `compiled_scripts`.
 /// It is **not** recommended to send the VM instance across threads, instead use `SyncFunction` if
@@ -101,13 +142,28 @@ pub struct ScriptCore {
     cross_modules: Mutex<Vec<Module>>,
 
     /// Special comments that upon found, toggle special compilation behavior.
-    compiler_special_settings: [&'static str; 2],
+    compiler_special_settings: [&'static str; 3],
 
     /// Global Script Variables.
     global_script_variables: Arc<DashMap<String, ValueWrapper>>,
 
-    /// Frame update callbacks. Each function is called every new frame, once for each window.
+    /// Frame update callbacks. Each function is called every new frame, once for each window,
+    /// unless it was registered for a specific window.
     on_frame_update_callbacks: Arc<DashMap<String, FrameUpdateCallback>>,
+
+    /// Hotkeys bound to script files, key being the virtual key code.
+    hotkey_bindings: DashMap<i32, HotkeyBinding>,
+
+    /// Watched scripts, key being the relative path and value being `true` for as long as the
+    /// watcher thread should keep running.
+    watched_scripts: DashMap<String, Arc<AtomicBool>>,
+
+    /// Thread keys, used for signaling boolean states between threads.
+    thread_keys: DashMap<String, bool>,
+
+    /// Running cancellable tasks, key being the token and value being `true` once the task has
+    /// been cancelled.
+    cancellable_tasks: DashMap<String, Arc<AtomicBool>>,
 }
 
 thread_safe_structs!(ScriptCore);
@@ -122,17 +178,55 @@ impl ScriptCore {
             compiler_special_settings: [
                 "//# EnableCompilerOption: NewThreadMain",
                 "//# DisableCompilerOption: CLIDiagnostics",
+                "//# EnableCompilerOption: UnlimitedBudget",
             ],
             global_script_variables: Default::default(),
             on_frame_update_callbacks: Default::default(),
+            hotkey_bindings: Default::default(),
+            watched_scripts: Default::default(),
+            thread_keys: Default::default(),
+            cancellable_tasks: Default::default(),
         }
     }
 
     /// Initializes the Rune runtime and compiles some code.
-    pub fn compile(
+    /// The rendered diagnostics are returned as the error, and unless disabled through
+    /// `compiler_special_settings`, also written to stderr.
+    pub fn compile_with_output(
         &self,
         source: &str,
         base_core: Arc<RwLock<BaseCore>>,
+    ) -> Result<Vm, String> {
+        let mut buffer = Buffer::no_color();
+        let result = self.compile(source, base_core, &mut buffer);
+        let diagnostics = String::from_utf8_lossy(buffer.as_slice());
+        if !source.contains(self.compiler_special_settings[1]) && !diagnostics.is_empty() {
+            // Never show colors as not all terminals handle it properly.
+            // Auto doesn't realize this.
+            let mut writer = StandardStream::stderr(ColorChoice::Never);
+            if let Err(error) = writer.write_all(buffer.as_slice()) {
+                log!(
+                    "[ERROR] Failed writing diagnostics to stderr, error: ",
+                    error
+                );
+            }
+        }
+
+        result.map_err(|error| {
+            if diagnostics.is_empty() {
+                error.to_string()
+            } else {
+                diagnostics.into_owned()
+            }
+        })
+    }
+
+    /// Compiles `source`, writing the diagnostics into `writer`.
+    fn compile<W: WriteColor>(
+        &self,
+        source: &str,
+        base_core: Arc<RwLock<BaseCore>>,
+        writer: &mut W,
     ) -> Result<Vm, Box<dyn Error>> {
         let Some(base_core_reader) = base_core.try_read() else {
             return Err("Failed reading BaseCore!".into());
@@ -176,11 +270,8 @@ impl ScriptCore {
             .with_diagnostics(&mut diagnostics)
             .build();
 
-        if !source.contains(self.compiler_special_settings[1]) && !diagnostics.is_empty() {
-            // Never show colors as not all terminals handle it properly.
-            // Auto doesn't realize this.
-            let mut writer = StandardStream::stderr(ColorChoice::Never);
-            diagnostics.emit(&mut writer, &sources)?;
+        if !diagnostics.is_empty() {
+            diagnostics.emit(writer, &sources)?;
         }
 
         let unit = result?;
@@ -194,27 +285,64 @@ impl ScriptCore {
     /// If the source of the script hasn't been compiled before, its compiled, cached and then the
     /// `main` function is called.
     /// Optionally it also sends the `source` to the active party.
+    /// Returns the compile error if compilation failed.
     pub fn execute(
         &self,
         source: String,
         base_core: Arc<RwLock<BaseCore>>,
         send_src_to_network: bool,
         force_new_thread: bool,
-    ) {
+    ) -> Option<String> {
+        self.execute_with_trust(
+            source,
+            base_core,
+            send_src_to_network,
+            force_new_thread,
+            true,
+        )
+    }
+
+    /// Same as `execute`, but for scripts received from the server.
+    /// Remote scripts can't opt out of `script_instruction_budget`.
+    pub fn execute_remote(
+        &self,
+        source: String,
+        base_core: Arc<RwLock<BaseCore>>,
+    ) -> Option<String> {
+        self.execute_with_trust(source, base_core, false, false, false)
+    }
+
+    /// Implementation of `execute`.
+    /// If `trusted` is `false`, the `UnlimitedBudget` compiler option is ignored.
+    fn execute_with_trust(
+        &self,
+        source: String,
+        base_core: Arc<RwLock<BaseCore>>,
+        send_src_to_network: bool,
+        force_new_thread: bool,
+        trusted: bool,
+    ) -> Option<String> {
         if source.is_empty() {
             log!("[WARN] Attempted to execute empty source, cancelling.");
-            return;
+            return None;
         }
 
         let Some(reader) = base_core.try_read() else {
             log!("[ERROR] Compilation failed because BaseCore is locked!");
-            return;
+            return None;
         };
 
         let use_new_thread = reader.get_config().get_use_new_rune_thread()
             || force_new_thread
             || source.contains(self.compiler_special_settings[0]);
 
+        // Read before execution, as the budget can't be lifted once the script is running.
+        let unlimited_budget = trusted && source.contains(self.compiler_special_settings[2]);
+        let budget = reader
+            .get_config()
+            .get_script_instruction_budget()
+            .filter(|_| !unlimited_budget);
+
         let source = self
             .add_imports(&source, reader.get_config().get_path())
             .unwrap_or(source);
@@ -225,12 +353,18 @@ impl ScriptCore {
             drop(reader);
 
             // Cached, run the main function without compiling.
-            self.exec_main(source, crosscom, send_src_to_network, use_new_thread);
-            return;
+            self.exec_main(
+                source,
+                crosscom,
+                send_src_to_network,
+                use_new_thread,
+                budget,
+            );
+            return None;
         }
 
         let start = std::time::Instant::now();
-        let compile = self.compile(&source, Arc::clone(&base_core));
+        let compile = self.compile_with_output(&source, Arc::clone(&base_core));
         if let Ok(vm) = compile {
             // Uncached source. Compile, store and run the main function.
             self.compiled_scripts.insert(hash, VMWrapper(vm));
@@ -238,15 +372,23 @@ impl ScriptCore {
             drop(reader);
 
             // Run main and print the elapsed time.
-            self.exec_main(source, crosscom, send_src_to_network, use_new_thread);
+            self.exec_main(
+                source,
+                crosscom,
+                send_src_to_network,
+                use_new_thread,
+                budget,
+            );
             log!(
                 "[Script Engine] Script compiled in ",
                 format!("{:.2?}!", start.elapsed())
             );
-            return;
+            return None;
         }
 
-        log!("[Script Engine] Compile error: ", compile.unwrap_err());
+        let error = compile.err()?;
+        log!("[Script Engine] Compile error: ", error);
+        Some(error)
     }
 
     /// Executes the main function found in `source`.
@@ -262,12 +404,17 @@ impl ScriptCore {
         crosscom: Arc<RwLock<CrossCom>>,
         send_src_to_network: bool,
         use_new_thread: bool,
+        budget: Option<usize>,
     ) {
         let Some(vm) = self.compiled_scripts.get(&source.get_hash()) else {
             return;
         };
 
         let main = vm.0.lookup_function(["main"]);
+
+        // Don't keep the cache entry borrowed while the script runs, otherwise clearing the cache
+        // from inside of the script deadlocks.
+        drop(vm);
         if let Err(error) = main {
             log!("[ERROR] Compile error when looking up main, error: ", error);
             return;
@@ -284,8 +431,25 @@ impl ScriptCore {
 
         let code = move || {
             log!("[Script Engine] Script executing...");
-            let execution = main_sync.unwrap().call::<(), ()>(()).into_result();
-            if let Err(error) = execution {
+            let main_sync = main_sync.unwrap();
+            let (execution, budget_exceeded) = match budget {
+                Some(budget) => runtime::budget::with(budget, || {
+                    let execution = main_sync.call::<(), ()>(());
+
+                    // Rune halts once the budget can't hand out any more instructions, so an
+                    // exhausted budget means that's why the script stopped.
+                    (execution, !runtime::budget::acquire().take())
+                })
+                .call(),
+                None => (main_sync.call::<(), ()>(()), false),
+            };
+
+            if let Err(error) = execution.into_result() {
+                if budget_exceeded {
+                    log!("[Script Engine] Budget exceeded, script stopped!");
+                    return;
+                }
+
                 log!("[ERROR] Compile error when executing main, error: ", error);
                 return;
             };
@@ -298,7 +462,7 @@ impl ScriptCore {
 
         if use_new_thread {
             log!("[Script Engine] Running script on a new thread...");
-            std::thread::spawn(move || {
+            WinUtils::spawn_worker(move || {
                 code();
                 log!("[Script Engine] Script finished executing!");
             });
@@ -425,14 +589,39 @@ impl ScriptCore {
 
     /// Adds a new `on_frame_update` callback to `self.on_frame_update`. If there is already a
     /// callback defined as `identifier`, then it's replaced.
+    /// If `pass_delta_time` is `true`, the current delta time is passed into the callback after
+    /// the rest of the parameters.
+    /// If `window` is set, the callback is only called for that window.
+    /// Callbacks with a lower `priority` are called first.
     pub fn register_frame_update_callback(
         &self,
         identifier: String,
         callback: SyncFunction,
         opt_param: Option<Value>,
+        pass_delta_time: bool,
+        window: Option<String>,
+        priority: i64,
     ) {
-        self.on_frame_update_callbacks
-            .insert(identifier, FrameUpdateCallback::new(callback, opt_param));
+        self.on_frame_update_callbacks.insert(
+            identifier,
+            FrameUpdateCallback::new(callback, opt_param, pass_delta_time, window, priority),
+        );
+    }
+
+    /// Sets the priority of the defined callback, callbacks with a lower priority are called
+    /// first.
+    pub fn set_frame_update_callback_priority(&self, identifier: &str, priority: i64) {
+        let Some(mut frame_update_callback) = self.on_frame_update_callbacks.get_mut(identifier)
+        else {
+            log!(
+                "[ERROR] No frame update callback is registered as \"",
+                identifier,
+                "\"!"
+            );
+            return;
+        };
+
+        frame_update_callback.priority = priority;
     }
 
     /// Removes the defined callback if present.
@@ -440,6 +629,205 @@ impl ScriptCore {
         self.on_frame_update_callbacks.remove(identifier);
     }
 
+    /// Clears all compiled scripts, forcing them to be recompiled on the next execution.
+    pub fn clear_compiled_scripts(&self) {
+        self.compiled_scripts.clear();
+    }
+
+    /// Returns the amount of compiled scripts that are cached.
+    pub fn get_compiled_scripts_count(&self) -> usize {
+        self.compiled_scripts.len()
+    }
+
+    /// Binds `key` to execute the script at `relative_path` when pressed.
+    /// If `key` is already bound, the old binding is replaced.
+    pub fn bind_hotkey(&self, key: &str, relative_path: String) {
+        let Some(vkey) = WinUtils::find_vkey_from_str(key) else {
+            log!("[ERROR] Invalid key: \"", key, "\"!");
+            return;
+        };
+
+        self.hotkey_bindings.insert(
+            vkey,
+            HotkeyBinding {
+                relative_path,
+                was_down: true,
+            },
+        );
+    }
+
+    /// Removes the hotkey binding for `key`, if present.
+    pub fn unbind_hotkey(&self, key: &str) {
+        let Some(vkey) = WinUtils::find_vkey_from_str(key) else {
+            log!("[ERROR] Invalid key: \"", key, "\"!");
+            return;
+        };
+
+        self.hotkey_bindings.remove(&vkey);
+    }
+
+    /// Checks all hotkey bindings and executes the scripts whose key was just pressed.
+    pub fn poll_hotkeys(&self, base_core: Arc<RwLock<BaseCore>>) {
+        if self.hotkey_bindings.is_empty() {
+            return;
+        }
+
+        let mut pressed_scripts = Vec::new();
+        for mut entry in self.hotkey_bindings.iter_mut() {
+            let is_down = WinUtils::is_vkey_down(*entry.key() as i64);
+            if is_down && !entry.was_down {
+                pressed_scripts.push(entry.relative_path.to_owned());
+            }
+
+            entry.was_down = is_down;
+        }
+
+        for relative_path in pressed_scripts {
+            let Some(reader) = base_core.try_read() else {
+                log!("[ERROR] BaseCore is locked, cannot execute hotkey script!");
+                continue;
+            };
+
+            let mut source = String::default();
+            if !reader
+                .get_config()
+                .get_file_content(&relative_path, &mut source)
+            {
+                log!(
+                    "[ERROR] Failed loading hotkey script \"",
+                    relative_path,
+                    "\"!"
+                );
+                continue;
+            }
+
+            drop(reader);
+            self.execute(source, Arc::clone(&base_core), false, false);
+        }
+    }
+
+    /// Starts watching the script at `relative_path`, executing it each time it's modified.
+    pub fn watch_script(&'static self, relative_path: String, base_core: Arc<RwLock<BaseCore>>) {
+        let Some(config) = base_core.try_read().map(|reader| reader.get_config()) else {
+            log!("[ERROR] BaseCore is locked, cannot watch script!");
+            return;
+        };
+
+        // Checking and inserting through the same entry, so concurrent calls can't both spawn a
+        // watcher for the same path.
+        let is_watching = match self.watched_scripts.entry(relative_path.to_owned()) {
+            Entry::Occupied(_) => {
+                log!("[WARN] \"", relative_path, "\" is already being watched!");
+                return;
+            }
+            Entry::Vacant(entry) => Arc::clone(&entry.insert(Arc::new(AtomicBool::new(true)))),
+        };
+
+        WinUtils::spawn_worker(move || {
+            let mut last_modified = config.get_file_modified_time(&relative_path);
+            while is_watching.load(Ordering::Relaxed) && !IS_UNLOADING.load(Ordering::Relaxed) {
+                std::thread::sleep(Duration::from_millis(500));
+
+                let modified = config.get_file_modified_time(&relative_path);
+                if modified.is_none() || modified == last_modified {
+                    continue;
+                }
+
+                last_modified = modified;
+                let mut source = String::default();
+                if config.get_file_content(&relative_path, &mut source) {
+                    self.execute(source, Arc::clone(&base_core), false, false);
+                }
+            }
+        });
+    }
+
+    /// Stops watching the script at `relative_path`.
+    pub fn unwatch_script(&self, relative_path: &str) {
+        let Some((_, is_watching)) = self.watched_scripts.remove(relative_path) else {
+            log!("[WARN] \"", relative_path, "\" isn't being watched!");
+            return;
+        };
+
+        is_watching.store(false, Ordering::Relaxed);
+    }
+
+    /// Creates a new thread key with the value `false`.
+    /// If the thread key already exists, its value is left untouched.
+    pub fn create_thread_key(&self, key: String) {
+        self.thread_keys.entry(key).or_insert(false);
+    }
+
+    /// Sets the value of an existing thread key.
+    pub fn set_thread_key(&self, key: &str, value: bool) {
+        let Some(mut thread_key) = self.thread_keys.get_mut(key) else {
+            log!("[ERROR] Thread key \"", key, "\" hasn't been created!");
+            return;
+        };
+
+        *thread_key = value;
+    }
+
+    /// Gets the value of a thread key, `None` if it hasn't been created.
+    pub fn get_thread_key(&self, key: &str) -> Option<bool> {
+        self.thread_keys.get(key).map(|value| *value)
+    }
+
+    /// Calls `function` on a new thread with `opt_param` and the token of the task, returning the
+    /// token.
+    /// The task is expected to check `is_task_cancelled` with its token and return once cancelled.
+    pub fn spawn_cancellable(
+        &'static self,
+        function: SyncFunction,
+        opt_param: Option<Value>,
+    ) -> String {
+        let mut token = StringUtils::get_random();
+        while self.cancellable_tasks.contains_key(&token) {
+            token = StringUtils::get_random();
+        }
+
+        let opt_param = opt_param.map(ValueWrapper);
+        self.cancellable_tasks
+            .insert(token.to_owned(), Arc::new(AtomicBool::new(false)));
+
+        let task_token = token.to_owned();
+        WinUtils::spawn_worker(move || {
+            if let Err(error) = function
+                .call::<_, ()>((opt_param.map(|value| value.0), task_token.as_str()))
+                .into_result()
+            {
+                log!(
+                    "[ERROR] Failed calling cancellable task \"",
+                    task_token,
+                    "\", error: ",
+                    error
+                );
+            }
+
+            self.cancellable_tasks.remove(&task_token);
+        });
+
+        token
+    }
+
+    /// Cancels the task behind `token`.
+    pub fn cancel_task(&self, token: &str) {
+        let Some(is_cancelled) = self.cancellable_tasks.get(token) else {
+            log!("[WARN] No task is running with the token \"", token, "\"!");
+            return;
+        };
+
+        is_cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Checks if the task behind `token` has been cancelled.
+    /// Returns `true` if no task is running with `token`.
+    pub fn is_task_cancelled(&self, token: &str) -> bool {
+        self.cancellable_tasks
+            .get(token)
+            .is_none_or(|is_cancelled| is_cancelled.load(Ordering::Relaxed))
+    }
+
     /// Calls all callbacks and passes in `window` and `ui`.
     /// If `window` and/or `ui` are `None`, then the callback was issued outside of a window.
     pub fn call_frame_update_callbacks(
@@ -452,20 +840,50 @@ impl ScriptCore {
         }
 
         let ui_ptr = ui.map(|ui| std::ptr::addr_of!(ui) as i64);
-        for entry in &*self.on_frame_update_callbacks {
-            let frame_update_callback_data = entry.value();
-            if let Err(error) = frame_update_callback_data
-                .callback
-                .call::<(Option<&Value>, Option<&str>, Option<i64>), ()>((
-                    frame_update_callback_data.opt_param.as_ref(),
-                    window,
-                    ui_ptr,
-                ))
-                .into_result()
+        let delta_time = DELTA_TIME.load(Ordering::Relaxed);
+
+        // Cloned so that no entry is locked while the callbacks run, as they may register or
+        // remove callbacks themselves.
+        let mut entries: Vec<(String, FrameUpdateCallback)> = self
+            .on_frame_update_callbacks
+            .iter()
+            .map(|entry| (entry.key().to_owned(), entry.value().clone()))
+            .collect();
+        entries.sort_by(
+            |(identifier, callback), (other_identifier, other_callback)| {
+                callback
+                    .priority
+                    .cmp(&other_callback.priority)
+                    .then_with(|| identifier.cmp(other_identifier))
+            },
+        );
+
+        for (identifier, frame_update_callback_data) in entries {
+            if let Some(target_window) = &frame_update_callback_data.window
+                && window != Some(target_window.as_str())
             {
+                continue;
+            }
+
+            let opt_param = frame_update_callback_data.opt_param.as_ref();
+            let result = if frame_update_callback_data.pass_delta_time {
+                frame_update_callback_data
+                    .callback
+                    .call::<(Option<&Value>, Option<&str>, Option<i64>, f32), ()>((
+                        opt_param, window, ui_ptr, delta_time,
+                    ))
+            } else {
+                frame_update_callback_data
+                    .callback
+                    .call::<(Option<&Value>, Option<&str>, Option<i64>), ()>((
+                        opt_param, window, ui_ptr,
+                    ))
+            };
+
+            if let Err(error) = result.into_result() {
                 log!(
                     "[ERROR] Failed calling frame update callback on \"",
-                    entry.key(),
+                    identifier,
                     "\", error: ",
                     error
                 );
@@ -473,6 +891,21 @@ impl ScriptCore {
         }
     }
 
+    /// Stops all script watchers, cancels all cancellable tasks and removes all frame update
+    /// callbacks, so that no more scripts are started before dynamic unloads.
+    pub fn stop_workers(&self) {
+        for is_watching in self.watched_scripts.iter() {
+            is_watching.store(false, Ordering::Relaxed);
+        }
+
+        for is_cancelled in self.cancellable_tasks.iter() {
+            is_cancelled.store(true, Ordering::Relaxed);
+        }
+
+        self.watched_scripts.clear();
+        self.on_frame_update_callbacks.clear();
+    }
+
     /// Returns `self.global_script_variables`.
     pub fn get_global_script_variables(&self) -> Arc<DashMap<String, ValueWrapper>> {
         Arc::clone(&self.global_script_variables)
@@ -6,9 +6,12 @@ use crate::{
     globals::*,
     mod_cores::base_core::BaseCore,
     utils::{
-        crosscom::CrossCom,
+        config::Config,
+        crosscom::{CrossCom, CrossComState},
+        cryptutils::CryptUtils,
         dynwidget::{SubWidgetType, WidgetType},
         extensions::{F32Ext, OptionExt, ResultExtensions},
+        prompter::{PromptResult, Prompter},
         runedetour::RDetour,
         scripting::rune_ext_structs::RuneDoubleResultPrimitive,
         stringutils::StringUtils,
@@ -30,7 +33,11 @@ use std::{
     str::FromStr,
     sync::{atomic::Ordering, Arc},
 };
-use windows::Win32::System::Threading::GetCurrentProcess;
+use windows::Win32::System::{
+    Console::GetConsoleWindow,
+    Memory::{VirtualProtect, PAGE_EXECUTE_READWRITE, PAGE_PROTECTION_FLAGS},
+    Threading::GetCurrentProcess,
+};
 use wmem::Memory;
 use zstring::ZString;
 
@@ -48,6 +55,8 @@ impl SystemModules {
         let base_core_reader = base_core.read();
         let script_core = base_core_reader.get_script_core();
         let config = base_core_reader.get_config();
+        let generic_hooks = base_core_reader.get_generic_hooks();
+        let script_ui_queues = base_core_reader.get_script_ui_queues();
         drop(base_core_reader);
 
         let mut module = Module::new();
@@ -63,6 +72,8 @@ impl SystemModules {
         let mut arctic_module = Module::with_crate(&zencstr!("Arctic").data)?;
         let mut std_module = Module::with_crate(&zencstr!("std").data)?;
         let mut mutex_module = Module::with_crate(&zencstr!("Mutex").data)?;
+        let mut json_module = Module::with_crate(&zencstr!("Json").data)?;
+        let mut net_module = Module::with_crate(&zencstr!("Net").data)?;
 
         module.ty::<RuneDoubleResultPrimitive>()?;
         module.ty::<MutexValue>()?;
@@ -116,20 +127,144 @@ impl SystemModules {
                 log!(data);
             })
             .build()?;
+        dynamic_module
+            .function("log_warn", |data: &str| Self::log_level(1, data))
+            .build()?;
+        dynamic_module
+            .function("log_error", |data: &str| Self::log_level(2, data))
+            .build()?;
+        dynamic_module
+            .function("log_level", Self::log_level)
+            .build()?;
+        dynamic_module
+            .function("clear_log", WinUtils::clear_log)
+            .build()?;
+        dynamic_module
+            .function("toast", move |text: String, seconds: f32| {
+                script_ui_queues.add_toast(text, seconds)
+            })
+            .build()?;
+
+        dynamic_module
+            .function("console_prompt", Self::console_prompt)
+            .build()?;
+
+        let base_core_clone = Arc::clone(&base_core);
+        dynamic_module
+            .function(
+                "inject_plugin_with_arg",
+                move |dll_name: String, arg: String| {
+                    base_core_clone
+                        .read()
+                        .get_arctic_core()
+                        .get()
+                        .unwrap_or_crash(zencstr!(
+                            "[ERROR] Unitialized Arctic instance inside of Script Engine!"
+                        ))
+                        .inject_plugin_with_arg(dll_name, Some(arg))
+                },
+            )
+            .build()?;
+
+        dynamic_module.function("exit", Self::exit).build()?;
+
+        let base_core_clone = Arc::clone(&base_core);
+        dynamic_module
+            .function("eject", move || crate::unload(Arc::clone(&base_core_clone)))
+            .build()?;
+        let base_core_clone = Arc::clone(&base_core);
+        dynamic_module
+            .function("unload", move || {
+                crate::unload(Arc::clone(&base_core_clone))
+            })
+            .build()?;
 
         dynamic_module
             .function("is_key_down", WinUtils::is_key_down)
             .build()?;
+        dynamic_module
+            .function("was_key_pressed", WinUtils::was_key_pressed)
+            .build()?;
+        dynamic_module
+            .function("is_vkey_down", WinUtils::is_vkey_down)
+            .build()?;
         dynamic_module
             .function("get_delta_time", || DELTA_TIME.load(Ordering::Relaxed))
             .build()?;
+        dynamic_module
+            .function("bind_hotkey", move |key: &str, relative_path: String| {
+                script_core.bind_hotkey(key, relative_path)
+            })
+            .build()?;
+        dynamic_module
+            .function("unbind_hotkey", move |key: &str| {
+                script_core.unbind_hotkey(key)
+            })
+            .build()?;
+
+        let base_core_clone = Arc::clone(&base_core);
+        dynamic_module
+            .function("watch_script", move |relative_path: String| {
+                script_core.watch_script(relative_path, Arc::clone(&base_core_clone))
+            })
+            .build()?;
+        dynamic_module
+            .function("unwatch_script", move |relative_path: &str| {
+                script_core.unwatch_script(relative_path)
+            })
+            .build()?;
+        dynamic_module
+            .function("create_thread_key", move |key: String| {
+                script_core.create_thread_key(key)
+            })
+            .build()?;
+        dynamic_module
+            .function("set_thread_key", move |key: &str, value: bool| {
+                script_core.set_thread_key(key, value)
+            })
+            .build()?;
+        dynamic_module
+            .function("get_thread_key", move |key: &str| {
+                script_core.get_thread_key(key)
+            })
+            .build()?;
         compiler_module
             .function("run_multi_threaded", Self::run_multi_threaded)
             .build()?;
+        compiler_module
+            .function("clear_cache", move || script_core.clear_compiled_scripts())
+            .build()?;
+        compiler_module
+            .function("cached_script_count", move || {
+                script_core.get_compiled_scripts_count() as i64
+            })
+            .build()?;
         task_module
             .function("sleep_secs", Self::sleep_secs)
             .build()?;
         task_module.function("sleep_ms", Self::sleep_ms).build()?;
+        task_module
+            .function(
+                "spawn_cancellable",
+                move |function: Function, opt_param: Option<Value>| {
+                    script_core.spawn_cancellable(
+                        function
+                            .into_sync()
+                            .into_result()
+                            .dynamic_expect(zencstr!("Failed turning Function into SyncFunction")),
+                        opt_param,
+                    )
+                },
+            )
+            .build()?;
+        task_module
+            .function("cancel", move |token: &str| script_core.cancel_task(token))
+            .build()?;
+        task_module
+            .function("is_cancelled", move |token: &str| {
+                script_core.is_task_cancelled(token)
+            })
+            .build()?;
         parse_module.function("i8", Self::r#as::<i8>).build()?;
         parse_module.function("u8", Self::r#as::<u8>).build()?;
         parse_module.function("i16", Self::r#as::<i16>).build()?;
@@ -149,20 +284,83 @@ impl SystemModules {
         math_module
             .function("pi", || std::f32::consts::PI)
             .build()?;
+        math_module.function("ease_in", Self::ease_in).build()?;
+        math_module.function("ease_out", Self::ease_out).build()?;
+        math_module
+            .function("ease_in_out", Self::ease_in_out)
+            .build()?;
+        math_module.function("damp", Self::damp).build()?;
 
         windows_module
             .function("get_cursor_xy", Self::get_cursor_xy)
             .build()?;
+        windows_module
+            .function("get_scroll_delta", || SCROLL_DELTA.load(Ordering::Relaxed))
+            .build()?;
+        windows_module
+            .function("is_mouse_button_down", WinUtils::is_mouse_button_down)
+            .build()?;
+        windows_module
+            .function("set_cursor_lock", move |enabled: bool| {
+                generic_hooks.set_cursor_lock(enabled)
+            })
+            .build()?;
+        windows_module
+            .function("freeze_cursor", move |frozen: bool| {
+                generic_hooks.freeze_cursor(frozen)
+            })
+            .build()?;
+        windows_module
+            .function("move_mouse", |dx: i32, dy: i32| {
+                WinUtils::move_mouse(dx, dy)
+            })
+            .build()?;
         windows_module
             .function("show_alert", |caption: &str, text: &str| {
                 WinUtils::display_message_box(caption, text, 0x00000010)
             })
             .build()?;
+        windows_module
+            .function("message_box", |caption: &str, text: &str, style: i64| {
+                WinUtils::message_box(caption, text, style as u32) as i64
+            })
+            .build()?;
+
+        // Message box styles and results, see the MessageBoxA documentation.
+        for (name, value) in [
+            ("MB_OK", 0x00000000i64),
+            ("MB_OKCANCEL", 0x00000001),
+            ("MB_ABORTRETRYIGNORE", 0x00000002),
+            ("MB_YESNOCANCEL", 0x00000003),
+            ("MB_YESNO", 0x00000004),
+            ("MB_RETRYCANCEL", 0x00000005),
+            ("MB_ICONERROR", 0x00000010),
+            ("MB_ICONQUESTION", 0x00000020),
+            ("MB_ICONWARNING", 0x00000030),
+            ("MB_ICONINFORMATION", 0x00000040),
+            ("IDOK", 1),
+            ("IDCANCEL", 2),
+            ("IDABORT", 3),
+            ("IDRETRY", 4),
+            ("IDIGNORE", 5),
+            ("IDYES", 6),
+            ("IDNO", 7),
+        ] {
+            windows_module.constant(name, value).build()?;
+        }
         windows_module
             .function("get_base_of_module", |module_name: &str| {
                 WinUtils::get_base_of(module_name) as i64
             })
             .build()?;
+        windows_module
+            .function("get_module_size", |module_name: &str| {
+                WinUtils::get_module_size(module_name) as i64
+            })
+            .build()?;
+        windows_module
+            .function("list_modules", WinUtils::list_modules)
+            .build()?;
         windows_module
             .function(
                 "get_address_of_symbol",
@@ -176,26 +374,165 @@ impl SystemModules {
                 },
             )
             .build()?;
+        windows_module
+            .function(
+                "get_address_of_ordinal",
+                |module_name: &str, ordinal: i64| {
+                    let Ok(ordinal) = u16::try_from(ordinal) else {
+                        log!(
+                            "[ERROR] Ordinal ",
+                            ordinal,
+                            " is out of range, expected 0-65535!"
+                        );
+                        return None;
+                    };
+
+                    WinUtils::get_symbol_by_ordinal(module_name, ordinal).map(|value| value as i64)
+                },
+            )
+            .build()?;
         memory_module.function("write", Self::write).build()?;
+        memory_module
+            .function("write_protected", Self::write_protected)
+            .build()?;
+        memory_module.function("nop", Self::nop).build()?;
+        memory_module
+            .function("write_bytes", Self::write_bytes)
+            .build()?;
         memory_module
             .function("read", Self::read_primitive)
             .build()?;
+        memory_module
+            .function("read_i8", |address| Self::read_as::<i8>(address) as i64)
+            .build()?;
+        memory_module
+            .function("read_i16", |address| Self::read_as::<i16>(address) as i64)
+            .build()?;
+        memory_module
+            .function("read_i32", |address| Self::read_as::<i32>(address) as i64)
+            .build()?;
+        memory_module
+            .function("read_i64", Self::read_as::<i64>)
+            .build()?;
+        memory_module
+            .function("read_u8", |address| Self::read_as::<u8>(address) as i64)
+            .build()?;
+        memory_module
+            .function("read_u16", |address| Self::read_as::<u16>(address) as i64)
+            .build()?;
+        memory_module
+            .function("read_u32", |address| Self::read_as::<u32>(address) as i64)
+            .build()?;
+        memory_module
+            .function("read_u64", |address| Self::read_as::<u64>(address) as i64)
+            .build()?;
+        memory_module
+            .function("read_f32", Self::read_as::<f32>)
+            .build()?;
+        memory_module
+            .function("read_f64", Self::read_as::<f64>)
+            .build()?;
         memory_module.function("scan", Self::pattern_scan).build()?;
+        memory_module
+            .function("scan_legacy", Self::pattern_scan_legacy)
+            .build()?;
+        memory_module
+            .function("scan_first", Self::pattern_scan_first)
+            .build()?;
+        memory_module
+            .function("resolve_rip", Self::resolve_rip)
+            .build()?;
+        let crosscom_clone = Arc::clone(&crosscom);
+        memory_module
+            .function(
+                "server_scan",
+                move |variable_name: &str, base_address: i64, fallback: Function| {
+                    Self::server_scan(
+                        &crosscom_clone,
+                        variable_name,
+                        base_address as usize,
+                        Box::new(move || {
+                            fallback
+                                .call::<_, i64>(())
+                                .into_result()
+                                .unwrap_or_else(|error| {
+                                    log!("[ERROR] Failed calling scan fallback, error: ", error);
+                                    0
+                                }) as usize
+                        }),
+                    ) as i64
+                },
+            )
+            .build()?;
+        memory_module
+            .function("to_module_offset", Self::to_module_offset)
+            .build()?;
+        memory_module
+            .function("from_module_offset", |offset: i64, module: &str| {
+                WinUtils::get_base_of(module) as i64 + offset
+            })
+            .build()?;
+        memory_module
+            .function("read_bytes", Self::read_bytes)
+            .build()?;
         memory_module
             .function("read_string", Self::read_string)
             .build()?;
         memory_module
-            .function("fn_call", FNCaller::call_auto)
+            .function("fn_call", |fn_ptr: i64, params: Vec<Value>| {
+                FNCaller::call_auto(fn_ptr, params, 0)
+            })
+            .build()?;
+        memory_module
+            .function("fn_call_raw", |fn_ptr: i64, params: Vec<i64>| {
+                FNCaller::call_auto_raw(fn_ptr, params, 0)
+            })
+            .build()?;
+        memory_module
+            .function("fn_call_convention", FNCaller::call_auto)
+            .build()?;
+        memory_module
+            .function("fn_call_raw_convention", FNCaller::call_auto_raw)
+            .build()?;
+        memory_module
+            .function("fn_call_mixed", FNCaller::call_mixed)
+            .build()?;
+        memory_module
+            .function("fn_call_mixed_typed", FNCaller::call_mixed_typed)
             .build()?;
         memory_module
-            .function("fn_call_raw", FNCaller::call_auto_raw)
+            .function("fn_call_f32", FNCaller::call_auto_f32)
+            .build()?;
+        memory_module
+            .function("fn_call_f64", FNCaller::call_auto_f64)
+            .build()?;
+        memory_module
+            .function("fn_call_raw_f32", FNCaller::call_auto_raw_f32)
+            .build()?;
+        memory_module
+            .function("fn_call_raw_f64", FNCaller::call_auto_raw_f64)
             .build()?;
         memory_module
             .function("hook_function", RDetour::install_detour_auto)
             .build()?;
+        memory_module
+            .function("hook_function_named", RDetour::install_detour_named)
+            .build()?;
+        memory_module
+            .function("hook_function_noorig", RDetour::install_detour_noorig)
+            .build()?;
         memory_module
             .function("drop_hook", RDetour::drop_rdetour_at)
             .build()?;
+        memory_module
+            .function("drop_hook_named", RDetour::drop_rdetour_named)
+            .build()?;
+        memory_module
+            .function("get_free_hook_count", RDetour::get_free_detour_count)
+            .build()?;
+        memory_module
+            .function("is_address_hooked", RDetour::is_address_hooked)
+            .build()?;
         memory_module
             .function("free_cstring", |ptr: i64| {
                 if ptr == 0 {
@@ -227,6 +564,60 @@ impl SystemModules {
             })
             .build()?;
 
+        config_module
+            .function("get_string", |key: &str| config.get_string(key))
+            .build()?;
+
+        config_module
+            .function("get_i64", |key: &str| config.get_i64(key))
+            .build()?;
+
+        config_module
+            .function("get_bool", |key: &str| config.get_bool(key))
+            .build()?;
+
+        config_module
+            .function("get_f64", |key: &str| config.get_f64(key))
+            .build()?;
+
+        config_module
+            .function(
+                "set_value",
+                |key: String, value: Value| match serde_jsonc::to_value(&value) {
+                    Ok(value) => config.set_value(key, value),
+                    Err(error) => log!(
+                        "[ERROR] Failed converting value for \"",
+                        key,
+                        "\" into JSON, error: ",
+                        error
+                    ),
+                },
+            )
+            .build()?;
+
+        config_module.function("save", || config.save()).build()?;
+
+        json_module
+            .function("parse", |json: &str| {
+                serde_jsonc::from_str::<Value>(json)
+                    .inspect_err(|error| log!("[ERROR] Failed parsing JSON, error: ", error))
+                    .ok()
+            })
+            .build()?;
+
+        json_module
+            .function("stringify", |value: Value| {
+                serde_jsonc::to_string(&value).unwrap_or_else(|error| {
+                    log!("[ERROR] Failed turning value into JSON, error: ", error);
+                    String::default()
+                })
+            })
+            .build()?;
+
+        net_module
+            .function("http_get", |url: &str| Self::http_get(url, config))
+            .build()?;
+
         let base_core_clone = Arc::clone(&base_core);
         arctic_module
             .function("inject_plugin", move |dll_name| {
@@ -255,6 +646,34 @@ impl SystemModules {
             })
             .build()?;
 
+        let base_core_clone = Arc::clone(&base_core);
+        arctic_module
+            .function("list_plugins", move || {
+                base_core_clone
+                    .read()
+                    .get_arctic_core()
+                    .get()
+                    .unwrap_or_crash(zencstr!(
+                        "[ERROR] Unitialized Arctic instance inside of Script Engine!"
+                    ))
+                    .list_active_plugins()
+            })
+            .build()?;
+
+        let base_core_clone = Arc::clone(&base_core);
+        arctic_module
+            .function("eject_plugin", move |dll_name: String| {
+                base_core_clone
+                    .read()
+                    .get_arctic_core()
+                    .get()
+                    .unwrap_or_crash(zencstr!(
+                        "[ERROR] Unitialized Arctic instance inside of Script Engine!"
+                    ))
+                    .eject_plugin(&dll_name)
+            })
+            .build()?;
+
         std_module
             .function("get_lines_from_string", |input: &str| {
                 input
@@ -345,26 +764,111 @@ impl SystemModules {
                     .send_script(source);
             })
             .build()?;
+        let crosscom_clone = Arc::clone(&crosscom);
         std_module
-            .function("malloc", |size| unsafe { libc::malloc(size) } as i64)
+            .function("send_message_to_group", move |message: &str| {
+                crosscom_clone
+                    .try_read()
+                    .unwrap_or_crash(zencstr!(
+                        "[ERROR] CrossCom is locked, cannot call std::send_message_to_group!"
+                    ))
+                    .send_message(message);
+            })
             .build()?;
-        std_module
-            .function("free", |ptr: i64| unsafe {
-                if ptr == 0 {
-                    log!("[ERROR] std::free called with a nullptr!");
-                    return;
-                }
 
-                if ptr < 0 {
-                    log!("[ERROR] std::free called with a negative pointer!");
-                    return;
-                }
+        let crosscom_clone = Arc::clone(&crosscom);
+        std_module
+            .function("get_community_scripts", move || {
+                Self::get_community_scripts(&crosscom_clone)
+            })
+            .build()?;
 
-                libc::free(ptr as *mut _);
+        let crosscom_clone = Arc::clone(&crosscom);
+        std_module
+            .function("crosscom_ping", move || {
+                crosscom_clone
+                    .try_read()
+                    .unwrap_or_crash(zencstr!(
+                        "[ERROR] CrossCom is locked, cannot call std::crosscom_ping!"
+                    ))
+                    .get_ping()
             })
             .build()?;
 
-        Ok(vec![
+        let crosscom_clone = Arc::clone(&crosscom);
+        std_module
+            .function("on_group_message", move |callback: Function| {
+                let callback = callback
+                    .into_sync()
+                    .into_result()
+                    .dynamic_expect(zencstr!("Failed turning Function into SyncFunction"));
+
+                crosscom_clone
+                    .try_read()
+                    .unwrap_or_crash(zencstr!(
+                        "[ERROR] CrossCom is locked, cannot call std::on_group_message!"
+                    ))
+                    .get_network_listener()
+                    .on_group_message(move |message| {
+                        let Err(error) = callback.call::<_, ()>((message,)).into_result() else {
+                            return;
+                        };
+
+                        log!(
+                            "[ERROR] Failed calling group message callback, error: ",
+                            error
+                        );
+                    });
+            })
+            .build()?;
+
+        let crosscom_clone = Arc::clone(&crosscom);
+        std_module
+            .function("on_channel_joined", move |callback: Function| {
+                let callback = callback
+                    .into_sync()
+                    .into_result()
+                    .dynamic_expect(zencstr!("Failed turning Function into SyncFunction"));
+
+                crosscom_clone
+                    .try_read()
+                    .unwrap_or_crash(zencstr!(
+                        "[ERROR] CrossCom is locked, cannot call std::on_channel_joined!"
+                    ))
+                    .get_network_listener()
+                    .on_channel_joined(move |channel| {
+                        let Err(error) = callback.call::<_, ()>((channel,)).into_result() else {
+                            return;
+                        };
+
+                        log!(
+                            "[ERROR] Failed calling channel joined callback, error: ",
+                            error
+                        );
+                    });
+            })
+            .build()?;
+
+        std_module
+            .function("malloc", |size| unsafe { libc::malloc(size) } as i64)
+            .build()?;
+        std_module
+            .function("free", |ptr: i64| unsafe {
+                if ptr == 0 {
+                    log!("[ERROR] std::free called with a nullptr!");
+                    return;
+                }
+
+                if ptr < 0 {
+                    log!("[ERROR] std::free called with a negative pointer!");
+                    return;
+                }
+
+                libc::free(ptr as *mut _);
+            })
+            .build()?;
+
+        Ok(vec![
             module,
             dynamic_module,
             compiler_module,
@@ -378,9 +882,34 @@ impl SystemModules {
             arctic_module,
             std_module,
             mutex_module,
+            json_module,
+            net_module,
         ])
     }
 
+    /// Sends a blocking HTTP GET request to `url` and returns the response body.
+    /// Returns `None` if `allow_http` is disabled in config.jsonc, or if the request failed.
+    /// On Wine and/or Proton, this may fail.
+    fn http_get(url: &str, config: &Config) -> Option<String> {
+        if !config.get_allow_http() {
+            log!("[ERROR] HTTP requests are disabled, set config.jsonc -> allow_http to true to enable them!");
+            return None;
+        }
+
+        reqwest::blocking::get(url)
+            .and_then(|response| response.error_for_status())
+            .and_then(|response| response.text())
+            .inspect_err(|error| {
+                log!(
+                    "[ERROR] HTTP GET request to \"",
+                    url,
+                    "\" failed, error: ",
+                    error
+                )
+            })
+            .ok()
+    }
+
     /// Defines a new global variable if not present, otherwise updates the existing variable.
     fn define_global(
         variable_name: String,
@@ -413,7 +942,7 @@ impl SystemModules {
             .into_result()
             .dynamic_expect(zencstr!("Failed turning Function into SyncFunction"));
 
-        std::thread::spawn(move || {
+        WinUtils::spawn_worker(move || {
             let Err(error) = function
                 .call::<_, ()>((opt_param.map(|value| value.0),))
                 .into_result()
@@ -433,19 +962,60 @@ impl SystemModules {
         WinUtils::ptr_to_string(address as _).unwrap_or_default()
     }
 
-    /// Scans for a pattern in memory.
-    fn pattern_scan(module: &str, hex_string: String) -> Vec<i64> {
-        let ptr = hex_string.as_ptr();
-        WinUtils::find_from_signature(
-            module,
-            &StringUtils::hex_string_to_bytes(hex_string)
-                .unwrap_or_crash(zencstr!("[ERROR] Failed converting hex string into bytes!")),
-            true,
-        )
-        .iter()
-        .map(|address| *address as i64)
-        .filter(|address| *address != 0 && *address != ptr as i64)
-        .collect()
+    /// Scans for an IDA-style pattern in memory, where `?` and `??` are wildcards.
+    fn pattern_scan(module: &str, pattern: &str) -> Vec<i64> {
+        let Some((bytes, mask)) = Self::pattern_to_bytes(pattern) else {
+            return Vec::new();
+        };
+
+        WinUtils::find_from_masked_signature(module, &bytes, &mask)
+            .into_iter()
+            .map(|address| address as i64)
+            .collect()
+    }
+
+    /// Same as `pattern_scan`, but returns the first match or `0` if nothing was found.
+    fn pattern_scan_first(module: &str, pattern: &str) -> i64 {
+        let Some((bytes, mask)) = Self::pattern_to_bytes(pattern) else {
+            return 0;
+        };
+
+        WinUtils::find_first_from_signature(module, &bytes, &mask)
+            .map_or(0, |address| address as i64)
+    }
+
+    /// Converts `pattern` into its bytes and mask, warning about `7F` as it used to be a
+    /// wildcard before IDA-style patterns were supported.
+    fn pattern_to_bytes(pattern: &str) -> Option<(Vec<u8>, Vec<bool>)> {
+        let (bytes, mask) = StringUtils::ida_pattern_to_bytes(pattern)?;
+        if bytes
+            .iter()
+            .zip(&mask)
+            .any(|(byte, is_masked)| *is_masked && *byte == 0x7F)
+        {
+            log!(
+                "[WARN] 7F in \"",
+                pattern,
+                "\" is matched as a literal byte and no longer as a wildcard, use ?? or Memory::scan_legacy instead!"
+            );
+        }
+
+        Some((bytes, mask))
+    }
+
+    /// Scans for a pattern in memory, `7F` and `??` being wildcards.
+    /// Deprecated in favor of `pattern_scan`, only kept for scripts relying on `7F` wildcards.
+    fn pattern_scan_legacy(module: &str, hex_string: String) -> Vec<i64> {
+        let Some(bytes) = StringUtils::hex_string_to_bytes(hex_string) else {
+            return Vec::new();
+        };
+
+        let ptr = bytes.as_ptr();
+        WinUtils::find_from_signature(module, &bytes, true)
+            .iter()
+            .map(|address| *address as i64)
+            .filter(|address| *address != 0 && *address != ptr as i64)
+            .collect()
     }
 
     /// Writes to the specified memory address.
@@ -462,74 +1032,130 @@ impl SystemModules {
             return;
         }
 
-        let current_process_handle = unsafe { GetCurrentProcess() };
+        if !Self::is_address_valid(address) {
+            return;
+        }
 
-        // Read one byte from the address just to see if it errors and potentially hinder crashes.
-        if let Err(error) =
-            Memory::read::<u8>(&current_process_handle, address as *const i64, Some(1))
-        {
+        let Some(bytes) = Self::value_to_bytes(data) else {
+            return;
+        };
+
+        Self::write_raw(address, bytes);
+    }
+
+    /// Same as `write`, but temporarily changes the protection of the memory at `address` to
+    /// `PAGE_EXECUTE_READWRITE` while writing.
+    /// The original protection is always restored afterwards.
+    fn write_protected(address: i64, data: Value) {
+        if address == 0 {
+            log!("[ERROR] Address passed into Memory::write_protected was null!");
+            return;
+        }
+
+        if !Self::is_address_valid(address) {
+            return;
+        }
+
+        let Some(bytes) = Self::value_to_bytes(data) else {
+            return;
+        };
+
+        Self::write_raw_protected(address, bytes);
+    }
+
+    /// Writes `count` NOP (`0x90`) instructions to `address`, temporarily making the memory
+    /// writable while doing so.
+    fn nop(address: i64, count: i64) {
+        const MAX_NOP_COUNT: i64 = 4096;
+
+        if address == 0 {
+            log!("[ERROR] Address passed into Memory::nop was null!");
+            return;
+        }
+
+        if !(1..=MAX_NOP_COUNT).contains(&count) {
             log!(
-                "[ERROR] Memory at address ",
+                "[ERROR] Count passed into Memory::nop has to be between 1 and ",
+                MAX_NOP_COUNT,
+                ", got ",
+                count,
+                "!"
+            );
+            return;
+        }
+
+        if !Self::is_address_valid(address) {
+            return;
+        }
+
+        Self::write_raw_protected(address, vec![0x90; count as usize]);
+    }
+
+    /// Changes the protection of the memory at `address` to `PAGE_EXECUTE_READWRITE`, writes
+    /// `bytes` and then restores the original protection.
+    fn write_raw_protected(address: i64, bytes: Vec<u8>) {
+        let len = bytes.len();
+        let mut old_protection = PAGE_PROTECTION_FLAGS::default();
+        if let Err(error) = unsafe {
+            VirtualProtect(
+                address as _,
+                len,
+                PAGE_EXECUTE_READWRITE,
+                &mut old_protection,
+            )
+        } {
+            log!(
+                "[ERROR] Failed changing protection at ",
                 format!("{:?}", address as *const i64),
-                " is not valid, error: ",
-                error
+                " from ",
+                format!("{:#X}", old_protection.0),
+                " to ",
+                format!("{:#X}", PAGE_EXECUTE_READWRITE.0),
+                ", error: ",
+                format!("{error:?}")
             );
             return;
         }
 
-        let on_error = |error: windows::core::Error| {
+        Self::write_raw(address, bytes);
+
+        let mut unused_protection = PAGE_PROTECTION_FLAGS::default();
+        if let Err(error) =
+            unsafe { VirtualProtect(address as _, len, old_protection, &mut unused_protection) }
+        {
             log!(
-                "[ERROR] Failed writing to memory address at ",
+                "[ERROR] Failed restoring protection at ",
                 format!("{:?}", address as *const i64),
+                " from ",
+                format!("{:#X}", PAGE_EXECUTE_READWRITE.0),
+                " to ",
+                format!("{:#X}", old_protection.0),
                 ", error: ",
                 format!("{error:?}")
             );
-        };
+        }
+    }
 
+    /// Converts `data` into the bytes that `write` should write.
+    /// Returns `None` if the type isn't supported.
+    fn value_to_bytes(data: Value) -> Option<Vec<u8>> {
         if let Ok(data_i64) = data.as_integer().into_result() {
-            if let Err(error) = Memory::write(
-                &current_process_handle,
-                address as _,
-                &(data_i64 as i32),
-                None,
-            ) {
-                on_error(error);
-            }
-
-            return;
+            return Some((data_i64 as i32).to_ne_bytes().to_vec());
         }
 
         if let Ok(data_usize) = data.as_usize().into_result() {
-            if let Err(error) =
-                Memory::write(&current_process_handle, address as _, &data_usize, None)
-            {
-                on_error(error);
-            }
-
-            return;
+            return Some(data_usize.to_ne_bytes().to_vec());
         }
 
         if let Ok(data_f64) = data.as_float().into_result() {
-            if let Err(error) = Memory::write(
-                &current_process_handle,
-                address as _,
-                &(data_f64 as f32),
-                None,
-            ) {
-                on_error(error);
-            }
-
-            return;
+            return Some((data_f64 as f32).to_ne_bytes().to_vec());
         }
 
-        let Ok(data_string) = data.to_owned().into_string().into_result() else {
-            return;
-        };
-
+        let data_string = data.into_string().into_result().ok()?;
         let Ok(data_string) = data_string.borrow_ref() else {
             log!("[ERROR] Invalid type to be written!");
             log!("[INFO] You may only use primitive values, strings and byte-strings!");
-            return;
+            return None;
         };
 
         let mut bytes = data_string.as_bytes().to_vec();
@@ -549,14 +1175,227 @@ impl SystemModules {
             }
         });
 
+        Some(bytes)
+    }
+
+    /// Writes `bytes` to `address` without any validation.
+    pub fn write_raw(address: i64, bytes: Vec<u8>) {
         if let Err(error) = Memory::write(
-            &current_process_handle,
+            &unsafe { GetCurrentProcess() },
             address as _,
             &bytes,
             Some(bytes.len()),
         ) {
-            on_error(error);
+            log!(
+                "[ERROR] Failed writing to memory address at ",
+                format!("{:?}", address as *const i64),
+                ", error: ",
+                format!("{error:?}")
+            );
+        }
+    }
+
+    /// Writes `bytes` to `address`.
+    /// Values outside of the `u8` range are logged and skipped.
+    fn write_bytes(address: i64, bytes: Vec<i64>) {
+        if address == 0 {
+            log!("[ERROR] Address passed into Memory::write_bytes was null!");
+            return;
+        }
+
+        if !Self::is_address_valid(address) {
+            return;
+        }
+
+        let bytes: Vec<u8> = bytes
+            .into_iter()
+            .filter_map(|byte| {
+                u8::try_from(byte)
+                    .map_err(|_| {
+                        log!(
+                            "[ERROR] Value ",
+                            byte,
+                            " is out of range for a byte, skipping!"
+                        )
+                    })
+                    .ok()
+            })
+            .collect();
+
+        Self::write_raw(address, bytes);
+    }
+
+    /// Reads one byte from `address` just to see if it errors and potentially hinder crashes.
+    fn is_address_valid(address: i64) -> bool {
+        let current_process_handle = unsafe { GetCurrentProcess() };
+        if let Err(error) =
+            Memory::read::<u8>(&current_process_handle, address as *const i64, Some(1))
+        {
+            log!(
+                "[ERROR] Memory at address ",
+                format!("{:?}", address as *const i64),
+                " is not valid, error: ",
+                error
+            );
+            return false;
+        }
+
+        true
+    }
+
+    /// Reads `len` bytes from `address`.
+    fn read_bytes(address: i64, len: i64) -> Vec<i64> {
+        if address == 0 {
+            log!("[ERROR] Address passed into Memory::read_bytes was null!");
+            return Vec::new();
+        }
+
+        if len <= 0 {
+            log!("[ERROR] Length passed into Memory::read_bytes has to be above 0!");
+            return Vec::new();
+        }
+
+        if !WinUtils::is_range_readable(address as usize, len as usize) {
+            log!(
+                "[ERROR] Memory at address ",
+                format!("{:?}", address as *const u8),
+                " isn't readable for ",
+                len,
+                " bytes!"
+            );
+            return Vec::new();
+        }
+
+        unsafe { std::slice::from_raw_parts(address as *const u8, len as usize) }
+            .iter()
+            .map(|byte| *byte as i64)
+            .collect()
+    }
+
+    /// Reads `len` bytes from `address`.
+    /// Returns an empty vector if `address` is null, or if any of the bytes are unreadable.
+    pub fn read_raw(address: i64, len: usize) -> Vec<u8> {
+        if address == 0 {
+            log!("[ERROR] Tried reading bytes from a nullptr!");
+            return Vec::new();
+        }
+
+        if !WinUtils::is_range_readable(address as usize, len) {
+            log!(
+                "[ERROR] Memory at address ",
+                format!("{:?}", address as *const u8),
+                " isn't readable for ",
+                len,
+                " bytes!"
+            );
+            return Vec::new();
+        }
+
+        unsafe { std::slice::from_raw_parts(address as *const u8, len) }.to_vec()
+    }
+
+    /// Reads a single `T` from `address`.
+    /// Returns `T::default()` if `address` is null or unreadable.
+    fn read_as<T: Default>(address: i64) -> T {
+        if address == 0 {
+            log!(
+                "[ERROR] Tried reading ",
+                std::any::type_name::<T>(),
+                " from a nullptr, returning the default value!"
+            );
+            return T::default();
+        }
+
+        if !WinUtils::is_range_readable(address as usize, std::mem::size_of::<T>()) {
+            log!(
+                "[ERROR] Memory at address ",
+                format!("{:?}", address as *const u8),
+                " isn't readable as ",
+                std::any::type_name::<T>(),
+                ", returning the default value!"
+            );
+            return T::default();
+        }
+
+        // Game addresses aren't guaranteed to be aligned for `T`.
+        unsafe { std::ptr::read_unaligned(address as *const T) }
+    }
+
+    /// Resolves a RIP-relative operand of the instruction at `address` into an absolute address.
+    /// `offset_to_disp` is the offset from `address` to the 32-bit displacement, and
+    /// `instruction_len` is the length of the whole instruction.
+    /// Returns `0` if the displacement couldn't be read.
+    fn resolve_rip(address: i64, offset_to_disp: i64, instruction_len: i64) -> i64 {
+        if address == 0 {
+            log!("[ERROR] resolve_rip called with a nullptr, returning 0!");
+            return 0;
+        }
+
+        let disp_address = address + offset_to_disp;
+        if !Self::is_address_valid(disp_address) {
+            return 0;
+        }
+
+        let disp = unsafe { std::ptr::read_unaligned(disp_address as *const i32) };
+        address + instruction_len + disp as i64
+    }
+
+    /// Tries to get the cached offset of `variable_name` from the server, returning
+    /// `base_address` plus the offset.
+    /// If the server doesn't have a valid offset for `variable_name`, `fallback` is called and its
+    /// address is returned instead.
+    /// Holds the CrossCom read lock while waiting up to 30 seconds for the server, so it must not
+    /// be called from frame update callbacks.
+    fn server_scan(
+        crosscom: &RwLock<CrossCom>,
+        variable_name: &str,
+        base_address: usize,
+        fallback: Box<dyn FnOnce() -> usize>,
+    ) -> usize {
+        let Some(crosscom) = crosscom.try_read() else {
+            log!("[ERROR] CrossCom is locked, using the fallback for Memory::server_scan!");
+            return fallback();
+        };
+
+        let offset = crosscom
+            .get_variables()
+            .get(variable_name)
+            .and_then(|offset| match offset.strip_prefix("0x") {
+                Some(hex) => usize::from_str_radix(hex, 16).ok(),
+                None => offset.parse().ok(),
+            });
+
+        drop(crosscom);
+        let Some(offset) = offset else {
+            return fallback();
+        };
+
+        base_address.checked_add(offset).unwrap_or_else(|| {
+            log!(
+                "[ERROR] Server offset for \"",
+                variable_name,
+                "\" overflows the base address, using the fallback!"
+            );
+            fallback()
+        })
+    }
+
+    /// Converts `address` into an offset relative to the base address of `module`.
+    /// Returns `-1` if `address` is below the base address.
+    fn to_module_offset(address: i64, module: &str) -> i64 {
+        let base = WinUtils::get_base_of(module) as i64;
+        if address < base {
+            log!(
+                "[ERROR] Address ",
+                format!("{:#X}", address),
+                " is below the base of \"",
+                module,
+                "\", returning -1!"
+            );
+            return -1;
         }
+
+        address - base
     }
 
     /// Reads a primitive from `address`.
@@ -580,39 +1419,155 @@ impl SystemModules {
         }
     }
 
-    /// Gets the X and Y-Coordinate of the cursor.
-    fn get_cursor_xy() -> Vec<RuneDoubleResultPrimitive> {
-        let mut vec = Vec::with_capacity(2);
-        let cursor_pos = WinUtils::get_cursor_pos();
-        let (x, y) = (cursor_pos.x, cursor_pos.y);
-        vec.push(RuneDoubleResultPrimitive::new(
-            x as i8, x, x as i64, x as f32, x as f64,
-        ));
-        vec.push(RuneDoubleResultPrimitive::new(
-            y as i8, y, y as i64, y as f32, y as f64,
-        ));
-        vec
-    }
+    /// Fetches all community scripts as `(name, summary, code)`.
+    /// Blocks until the server responds, so this should only be called from
+    /// `run_multi_threaded`.
+    /// Returns an empty vector if not connected to the server.
+    fn get_community_scripts(crosscom: &RwLock<CrossCom>) -> Vec<(String, String, String)> {
+        let Some(crosscom) = crosscom.try_read() else {
+            log!("[ERROR] CrossCom is locked, cannot fetch community scripts!");
+            return Vec::new();
+        };
 
-    /// Attempts to parse the given data as a number.
-    fn r#as<T: FromStr + Debug + Default>(data: &str) -> T
-    where
-        <T as FromStr>::Err: Display,
-    {
-        data.parse().unwrap_or_else(|error| {
-            log!(
-                "[ERROR] Failed parsing \"",
-                data,
-                "\", returning ",
-                std::any::type_name::<T>(),
-                "::default(). Error: ",
-                error
-            );
-            T::default()
-        })
+        if crosscom.get_state() != CrossComState::Connected {
+            log!("[ERROR] Not connected to the server, cannot fetch community scripts!");
+            return Vec::new();
+        }
+
+        crosscom
+            .get_community_content()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|item| (item.name, item.summary, CryptUtils::decrypt(&item.code)))
+            .collect()
     }
 
-    /// Puts the calling task to sleep for a few seconds.
+    /// Prompts the user through the console and returns the response.
+    /// If `valid_responses` isn't empty, `None` is returned for any response not in it.
+    /// Returns `None` if there's no console to prompt with.
+    /// While the console command loop is running, the next line it reads is used as the response
+    /// instead of being run as a command.
+    fn console_prompt(message: &str, valid_responses: Vec<String>) -> Option<String> {
+        if unsafe { GetConsoleWindow() }.0 == 0 {
+            log!("[ERROR] There's no console window, cannot call dynamic::console_prompt!");
+            return None;
+        }
+
+        let mut prompter = if valid_responses.is_empty() {
+            Prompter::new_any_response(message)
+        } else {
+            Prompter::new(
+                message,
+                valid_responses.iter().map(String::as_str).collect(),
+            )
+        };
+
+        if !IS_CONSOLE_LOOP_ACTIVE.load(Ordering::Relaxed) {
+            return prompter.prompt().map(PromptResult::into_response);
+        }
+
+        // The console command loop owns `stdin`, so the answer has to come from it.
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let mut console_prompt_sender = CONSOLE_PROMPT_SENDER.lock();
+        if IS_UNLOADING.load(Ordering::Relaxed) {
+            log!("[ERROR] dynamic is unloading, cannot call dynamic::console_prompt!");
+            return None;
+        }
+
+        if console_prompt_sender.is_some() {
+            log!("[ERROR] Another script is already waiting on dynamic::console_prompt!");
+            return None;
+        }
+
+        *console_prompt_sender = Some(sender);
+        drop(console_prompt_sender);
+
+        prompter
+            .prompt_from(&receiver)
+            .map(PromptResult::into_response)
+    }
+
+    /// Closes the process, same as the `exit` console command.
+    fn exit() {
+        std::process::exit(0)
+    }
+
+    /// Logs `data` with a prefix based on `level`.
+    /// `0` is info, `1` is a warning and `2` is an error.
+    fn log_level(level: i64, data: &str) {
+        match level {
+            0 => log!("[INFO] ", data),
+            1 => log!("[WARN] ", data),
+            2 => log!("[ERROR] ", data),
+            _ => {
+                log!("[WARN] Unknown log level ", level, ", logging as info!");
+                log!("[INFO] ", data);
+            }
+        }
+    }
+
+    /// Quadratic ease-in, `t` is clamped to `[0, 1]`.
+    fn ease_in(t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        t * t
+    }
+
+    /// Quadratic ease-out, `t` is clamped to `[0, 1]`.
+    fn ease_out(t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        1.0 - (1.0 - t) * (1.0 - t)
+    }
+
+    /// Quadratic ease-in-out, `t` is clamped to `[0, 1]`.
+    fn ease_in_out(t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        if t < 0.5 {
+            2.0 * t * t
+        } else {
+            1.0 - (-2.0 * t + 2.0).powi(2) / 2.0
+        }
+    }
+
+    /// Frame-rate independent smoothing from `current` towards `target`.
+    /// `smoothing` is the fraction of the distance left after one second, so `0.0` snaps straight
+    /// to `target` and `1.0` never moves away from `current`.
+    fn damp(current: f32, target: f32, smoothing: f32, dt: f32) -> f32 {
+        current.lerp(target, 1.0 - smoothing.powf(dt))
+    }
+
+    /// Gets the X and Y-Coordinate of the cursor.
+    fn get_cursor_xy() -> Vec<RuneDoubleResultPrimitive> {
+        let mut vec = Vec::with_capacity(2);
+        let cursor_pos = WinUtils::get_cursor_pos();
+        let (x, y) = (cursor_pos.x, cursor_pos.y);
+        vec.push(RuneDoubleResultPrimitive::new(
+            x as i8, x, x as i64, x as f32, x as f64,
+        ));
+        vec.push(RuneDoubleResultPrimitive::new(
+            y as i8, y, y as i64, y as f32, y as f64,
+        ));
+        vec
+    }
+
+    /// Attempts to parse the given data as a number.
+    fn r#as<T: FromStr + Debug + Default>(data: &str) -> T
+    where
+        <T as FromStr>::Err: Display,
+    {
+        data.parse().unwrap_or_else(|error| {
+            log!(
+                "[ERROR] Failed parsing \"",
+                data,
+                "\", returning ",
+                std::any::type_name::<T>(),
+                "::default(). Error: ",
+                error
+            );
+            T::default()
+        })
+    }
+
+    /// Puts the calling task to sleep for a few seconds.
     fn sleep_secs(seconds: u64) {
         std::thread::sleep(std::time::Duration::from_secs(seconds));
     }
@@ -635,6 +1590,9 @@ impl UIModules {
     ) -> Result<Module, ContextError> {
         let base_core_reader = base_core.read();
         let script_core = base_core_reader.get_script_core();
+        let crosscom = base_core_reader.get_crosscom();
+        let config = base_core_reader.get_config();
+        let script_ui_queues = base_core_reader.get_script_ui_queues();
         drop(base_core_reader);
 
         let mut module = Module::with_crate(&zencstr!("ui").data)?; // <-- TODO: Rename to `UI`.
@@ -662,6 +1620,19 @@ impl UIModules {
             )
             .build()?;
 
+        module
+            .function(
+                "add_wrapped_label",
+                |(window_name, identifier): (String, String), content: String, wrap_width: f32| {
+                    custom_window_utils.add_widget(
+                        &window_name,
+                        identifier,
+                        WidgetType::LabelWrapped(ZString::new(content), wrap_width, 0),
+                    )
+                },
+            )
+            .build()?;
+
         module
             .function(
                 "add_bold_label",
@@ -793,6 +1764,48 @@ impl UIModules {
             })
             .build()?;
 
+        module
+            .function(
+                "set_f32_slider_value",
+                |window_name: &str, identifier, value| {
+                    custom_window_utils.set_f32_slider_value(window_name, identifier, value)
+                },
+            )
+            .build()?;
+
+        module
+            .function(
+                "set_i32_slider_value",
+                |window_name: &str, identifier, value| {
+                    custom_window_utils.set_i32_slider_value(window_name, identifier, value)
+                },
+            )
+            .build()?;
+
+        module
+            .function(
+                "set_combobox_items",
+                |window_name: &str, identifier, items| {
+                    custom_window_utils.set_combobox_items(window_name, identifier, items)
+                },
+            )
+            .build()?;
+
+        module
+            .function("get_checkbox_value", |window_name: &str, identifier| {
+                custom_window_utils.get_checkbox_value(window_name, identifier)
+            })
+            .build()?;
+
+        module
+            .function(
+                "set_checkbox_value",
+                |window_name: &str, identifier, checked| {
+                    custom_window_utils.set_checkbox_value(window_name, identifier, checked)
+                },
+            )
+            .build()?;
+
         module
             .function("remove_widget", |window_name: &str, identifier| {
                 custom_window_utils.remove_widget(window_name, identifier)
@@ -853,6 +1866,52 @@ impl UIModules {
             )
             .build()?;
 
+        module
+            .function(
+                "add_image_from_bytes",
+                |(window_name, identifier): (String, String),
+                 bytes: Vec<i64>,
+                 (width, height),
+                 callback,
+                 opt_param| {
+                    let Ok(bytes) = bytes
+                        .into_iter()
+                        .map(u8::try_from)
+                        .collect::<Result<Vec<u8>, _>>()
+                    else {
+                        log!(
+                            "[ERROR] Image bytes for \"",
+                            identifier,
+                            "\" must be between 0 and 255, image not added!"
+                        );
+                        return;
+                    };
+                    let Some(image_path) = custom_window_utils.cache_image_from_bytes(
+                        &window_name,
+                        &identifier,
+                        &bytes,
+                    ) else {
+                        return;
+                    };
+
+                    custom_window_utils.add_widget(
+                        &window_name,
+                        identifier.to_owned(),
+                        WidgetType::Image(
+                            image_path,
+                            width,
+                            height,
+                            false,
+                            false,
+                            Self::function_into_rc_sync(callback, identifier),
+                            Rc::new(opt_param),
+                            false,
+                        ),
+                    )
+                },
+            )
+            .build()?;
+
         module
             .function(
                 "add_image_overlay",
@@ -920,12 +1979,132 @@ impl UIModules {
             )
             .build()?;
 
+        module
+            .function("set_window_position", |window_name: &str, x, y| {
+                custom_window_utils.set_window_position(window_name, [x, y])
+            })
+            .build()?;
+
+        module
+            .function("get_window_position", |window_name: &str| {
+                custom_window_utils
+                    .get_window_position(window_name)
+                    .map(|[x, y]| (x, y))
+            })
+            .build()?;
+
+        module
+            .function("is_window_focused", |window_name: &str| {
+                custom_window_utils.is_window_focused(window_name)
+            })
+            .build()?;
+        module
+            .function("is_window_hovered", |window_name: &str| {
+                custom_window_utils.is_window_hovered(window_name)
+            })
+            .build()?;
+
+        module
+            .function("set_window_open", |window_name: &str, open| {
+                custom_window_utils.set_window_open(window_name, open)
+            })
+            .build()?;
+
+        module
+            .function("is_window_open", |window_name: &str| {
+                custom_window_utils.is_window_open(window_name)
+            })
+            .build()?;
+
+        module
+            .function(
+                "set_window_flags",
+                |window_name: &str, no_resize, no_title_bar, no_move| {
+                    custom_window_utils.set_window_flags(
+                        window_name,
+                        no_resize,
+                        no_title_bar,
+                        no_move,
+                    )
+                },
+            )
+            .build()?;
+
+        module
+            .function("set_window_overlay", |window_name: &str, overlay| {
+                custom_window_utils.set_window_overlay(window_name, overlay)
+            })
+            .build()?;
+
+        module
+            .function(
+                "set_software_cursor_enabled",
+                |window_name: &str, enabled| {
+                    custom_window_utils.set_software_cursor_enabled(window_name, enabled)
+                },
+            )
+            .build()?;
+
+        module
+            .function("register_font", move |relative_path: String, size: f32| {
+                script_ui_queues.register_font(relative_path, size)
+            })
+            .build()?;
+
+        // Blocks until answered, so it refuses to run on the render thread which draws the prompt.
+        module
+            .function("prompt", move |title: String, label: String| {
+                if RENDER_THREAD_ID.get() == Some(&std::thread::current().id()) {
+                    log!("[ERROR] ui::prompt blocks until answered and can't be called from the render thread, call it from run_multi_threaded instead!");
+                    return None;
+                }
+
+                script_ui_queues
+                    .add_prompt(title, label)
+                    .recv()
+                    .ok()
+                    .flatten()
+            })
+            .build()?
+            .docs([
+                "Opens a modal asking for text input, returning the input or `None` if cancelled.",
+                "",
+                "Blocks until answered, so it must be called from `run_multi_threaded`.",
+                "On the render thread, like in frame update callbacks, it returns `None` instead.",
+            ])?;
+
         module
             .function("clear_cached_images", || {
                 custom_window_utils.clear_cached_images()
             })
             .build()?;
 
+        module
+            .function("get_image_size", move |image_path: &str| {
+                custom_window_utils
+                    .get_image_size(image_path, config.get_path())
+                    .map(|[width, height]| vec![width as f32, height as f32])
+            })
+            .build()?;
+
+        module
+            .function("set_gif_paused", |window_name, identifier, paused| {
+                custom_window_utils.set_gif_paused(window_name, identifier, paused)
+            })
+            .build()?;
+
+        module
+            .function("set_gif_frame", |window_name, identifier, frame| {
+                custom_window_utils.set_gif_frame(window_name, identifier, frame)
+            })
+            .build()?;
+
+        module
+            .function("unload_image", move |image_path: &str| {
+                custom_window_utils.remove_cached_image(image_path, config.get_path())
+            })
+            .build()?;
+
         module
             .function("hide_widgets", |identifiers| {
                 custom_window_utils.hide_widgets(identifiers)
@@ -938,6 +2117,18 @@ impl UIModules {
             })
             .build()?;
 
+        module
+            .function("disable_widgets", |window_name: &str, identifiers| {
+                custom_window_utils.set_widgets_disabled(window_name, identifiers)
+            })
+            .build()?;
+
+        module
+            .function("enable_widgets", |window_name: &str, identifiers| {
+                custom_window_utils.set_widgets_enabled(window_name, identifiers)
+            })
+            .build()?;
+
         module
             .function(
                 "add_input_text_multiline",
@@ -980,6 +2171,54 @@ impl UIModules {
             )
             .build()?;
 
+        module
+            .function(
+                "move_widget",
+                |window_name: &str, identifier: &str, new_index: i64| {
+                    custom_window_utils.move_widget(
+                        window_name,
+                        identifier,
+                        new_index.max(0) as usize,
+                    )
+                },
+            )
+            .build()?;
+
+        let crosscom_clone = Arc::clone(&crosscom);
+        module
+            .function("get_current_channel", move || {
+                crosscom_clone
+                    .try_read()
+                    .unwrap_or_crash(zencstr!(
+                        "[ERROR] CrossCom is locked, cannot call ui::get_current_channel!"
+                    ))
+                    .get_current_channel()
+                    .try_borrow()
+                    .map(|channel| channel.to_owned())
+                    .unwrap_or_default()
+            })
+            .build()?;
+
+        module
+            .function("join_channel", move |channel: String| {
+                if !CrossCom::is_valid_channel(&channel) {
+                    log!(
+                        "[ERROR] Channel \"",
+                        channel,
+                        "\" must start with #, contain no whitespaces and be at least 4 characters long!"
+                    );
+                    return;
+                }
+
+                crosscom
+                    .try_read()
+                    .unwrap_or_crash(zencstr!(
+                        "[ERROR] CrossCom is locked, cannot call ui::join_channel!"
+                    ))
+                    .join_channel(channel);
+            })
+            .build()?;
+
         module
             .function("is_cursor_in_ui", || {
                 IS_CURSOR_IN_UI.load(Ordering::Relaxed)
@@ -1013,6 +2252,43 @@ impl UIModules {
             )
             .build()?;
 
+        module
+            .function(
+                "add_tab_bar",
+                move |(window_name, section_identifier): (String, String),
+                      call_once: Function,
+                      opt_param: Option<Value>| {
+                    Self::add_sub_widget(
+                        &window_name,
+                        section_identifier,
+                        SubWidgetType::TabBar,
+                        call_once,
+                        opt_param,
+                        custom_window_utils,
+                    );
+                },
+            )
+            .build()?;
+
+        module
+            .function(
+                "add_tab_item",
+                move |(window_name, section_identifier): (String, String),
+                      text: String,
+                      call_once: Function,
+                      opt_param: Option<Value>| {
+                    Self::add_sub_widget(
+                        &window_name,
+                        section_identifier,
+                        SubWidgetType::TabItem(ZString::new(text)),
+                        call_once,
+                        opt_param,
+                        custom_window_utils,
+                    );
+                },
+            )
+            .build()?;
+
         module
             .function(
                 "add_checkbox",
@@ -1058,6 +2334,268 @@ impl UIModules {
             )
             .build()?;
 
+        module
+            .function(
+                "add_radio_group",
+                |(window_name, identifier): (String, String),
+                 text: String,
+                 (items, selected_index),
+                 on_value_changed: Function,
+                 opt_param: Option<Value>| {
+                    custom_window_utils.add_widget(
+                        &window_name,
+                        identifier.to_owned(),
+                        WidgetType::RadioGroup(
+                            ZString::new(text),
+                            selected_index,
+                            items,
+                            Self::function_into_rc_sync(on_value_changed, identifier),
+                            Rc::new(opt_param),
+                        ),
+                    )
+                },
+            )
+            .build()?;
+
+        module
+            .function("get_radio_group_value", |window_name: &str, identifier| {
+                custom_window_utils.get_radio_group_value(window_name, identifier)
+            })
+            .build()?;
+
+        module
+            .function(
+                "add_progress_bar",
+                |(window_name, identifier): (String, String),
+                 fraction: f32,
+                 overlay_text: Option<String>| {
+                    custom_window_utils.add_widget(
+                        &window_name,
+                        identifier,
+                        WidgetType::ProgressBar(fraction.clamp(0.0, 1.0), overlay_text),
+                    )
+                },
+            )
+            .build()?;
+
+        module
+            .function(
+                "update_progress_bar",
+                |window_name: &str, identifier, fraction| {
+                    custom_window_utils.update_progress_bar(window_name, identifier, fraction)
+                },
+            )
+            .build()?;
+
+        module
+            .function(
+                "add_color_picker",
+                |(window_name, identifier): (String, String),
+                 text: String,
+                 (r, g, b, a),
+                 alpha: bool,
+                 on_value_changed: Function,
+                 opt_param: Option<Value>| {
+                    custom_window_utils.add_widget(
+                        &window_name,
+                        identifier.to_owned(),
+                        WidgetType::ColorPicker(
+                            ZString::new(text),
+                            [r, g, b, a],
+                            alpha,
+                            Self::function_into_rc_sync(on_value_changed, identifier),
+                            Rc::new(opt_param),
+                        ),
+                    )
+                },
+            )
+            .build()?;
+
+        module
+            .function("get_color_picker_value", |window_name: &str, identifier| {
+                custom_window_utils.get_color_picker_value(window_name, identifier)
+            })
+            .build()?;
+
+        module
+            .function(
+                "add_list_box",
+                |(window_name, identifier): (String, String),
+                 text: String,
+                 items: Vec<String>,
+                 on_value_changed: Function,
+                 opt_param: Option<Value>| {
+                    custom_window_utils.add_widget(
+                        &window_name,
+                        identifier.to_owned(),
+                        WidgetType::ListBox(
+                            ZString::new(text),
+                            vec![false; items.len()],
+                            items,
+                            Self::function_into_rc_sync(on_value_changed, identifier),
+                            Rc::new(opt_param),
+                        ),
+                    )
+                },
+            )
+            .build()?;
+
+        module
+            .function("get_list_box_selection", |window_name: &str, identifier| {
+                custom_window_utils.get_list_box_selection(window_name, identifier)
+            })
+            .build()?;
+
+        module
+            .function(
+                "add_input_text",
+                |(window_name, identifier): (String, String),
+                 text: String,
+                 (mut default_value, max_len): (String, usize),
+                 on_value_changed: Function,
+                 opt_param: Option<Value>| {
+                    StringUtils::truncate_to_chars(&mut default_value, max_len);
+
+                    custom_window_utils.add_widget(
+                        &window_name,
+                        identifier.to_owned(),
+                        WidgetType::InputText(
+                            ZString::new(text),
+                            default_value,
+                            max_len,
+                            Self::function_into_rc_sync(on_value_changed, identifier),
+                            Rc::new(opt_param),
+                        ),
+                    )
+                },
+            )
+            .build()?;
+
+        module
+            .function("get_input_text_value", |window_name: &str, identifier| {
+                custom_window_utils.get_input_text_value(window_name, identifier)
+            })
+            .build()?;
+
+        module
+            .function(
+                "add_password_input",
+                |(window_name, identifier): (String, String),
+                 text: String,
+                 on_value_changed: Function,
+                 opt_param: Option<Value>| {
+                    custom_window_utils.add_widget(
+                        &window_name,
+                        identifier.to_owned(),
+                        WidgetType::Password(
+                            ZString::new(text),
+                            String::default(),
+                            Self::function_into_rc_sync(on_value_changed, identifier),
+                            Rc::new(opt_param),
+                        ),
+                    )
+                },
+            )
+            .build()?;
+
+        module
+            .function("get_password_value", |window_name: &str, identifier| {
+                custom_window_utils.get_password_value(window_name, identifier)
+            })
+            .build()?;
+
+        module
+            .function(
+                "add_plot",
+                |(window_name, identifier): (String, String),
+                 text: String,
+                 scale_min: f32,
+                 scale_max: f32| {
+                    custom_window_utils.add_widget(
+                        &window_name,
+                        identifier,
+                        WidgetType::PlotLines(ZString::new(text), Vec::new(), scale_min, scale_max),
+                    )
+                },
+            )
+            .build()?;
+
+        module
+            .function("push_plot_value", |window_name: &str, identifier, value| {
+                custom_window_utils.push_plot_value(window_name, identifier, value)
+            })
+            .build()?;
+
+        module
+            .function(
+                "add_log_panel",
+                |(window_name, identifier): (String, String), height: f32| {
+                    custom_window_utils.add_widget(
+                        &window_name,
+                        identifier,
+                        WidgetType::LogPanel(Vec::new(), height),
+                    )
+                },
+            )
+            .build()?;
+
+        module
+            .function(
+                "push_log_panel_line",
+                |window_name: &str, identifier, line| {
+                    custom_window_utils.push_log_panel_line(window_name, identifier, line)
+                },
+            )
+            .build()?;
+
+        module
+            .function("clear_log_panel", |window_name: &str, identifier| {
+                custom_window_utils.clear_log_panel(window_name, identifier)
+            })
+            .build()?;
+
+        module
+            .function(
+                "add_table",
+                |(window_name, identifier): (String, String),
+                 headers: Vec<String>,
+                 rows: Vec<Vec<String>>| {
+                    // ImGui only supports tables with 1 to 64 columns.
+                    if headers.is_empty() || headers.len() > 64 {
+                        log!("[ERROR] Tables must have between 1 and 64 columns!");
+                        return;
+                    }
+
+                    custom_window_utils.add_widget(
+                        &window_name,
+                        identifier,
+                        WidgetType::Table(headers, rows),
+                    )
+                },
+            )
+            .build()?;
+
+        module
+            .function("set_table_rows", |window_name: &str, identifier, rows| {
+                custom_window_utils.set_table_rows(window_name, identifier, rows)
+            })
+            .build()?;
+
+        module
+            .function("set_tooltip", |window_name: &str, identifier, text| {
+                custom_window_utils.set_widget_tooltip(window_name, identifier, text)
+            })
+            .build()?;
+
+        module
+            .function(
+                "set_widget_text_color",
+                |window_name: &str, identifier, (r, g, b, a)| {
+                    custom_window_utils.set_widget_text_color(window_name, identifier, [r, g, b, a])
+                },
+            )
+            .build()?;
+
         module
             .function("set_color_preset_for", |window_name: String, preset| {
                 custom_window_utils.set_color_preset_for(window_name, preset)
@@ -1066,15 +2604,56 @@ impl UIModules {
         module
             .function(
                 "register_frame_update_callback",
-                |identifier: String, callback, opt_param| {
+                |identifier: String, callback, opt_param, priority: i64| {
+                    script_core.register_frame_update_callback(
+                        identifier.to_owned(),
+                        Self::function_into_sync(callback, identifier),
+                        opt_param,
+                        false,
+                        None,
+                        priority,
+                    );
+                },
+            )
+            .build()?;
+        module
+            .function(
+                "register_frame_update_callback_dt",
+                |identifier: String, callback, opt_param, priority: i64| {
                     script_core.register_frame_update_callback(
                         identifier.to_owned(),
                         Self::function_into_sync(callback, identifier),
                         opt_param,
+                        true,
+                        None,
+                        priority,
                     );
                 },
             )
             .build()?;
+        module
+            .function(
+                "register_frame_update_callback_for",
+                |window_name: String, identifier: String, callback, opt_param, priority: i64| {
+                    script_core.register_frame_update_callback(
+                        identifier.to_owned(),
+                        Self::function_into_sync(callback, identifier),
+                        opt_param,
+                        false,
+                        Some(window_name),
+                        priority,
+                    );
+                },
+            )
+            .build()?;
+        module
+            .function(
+                "set_frame_update_callback_priority",
+                |identifier: &str, priority: i64| {
+                    script_core.set_frame_update_callback_priority(identifier, priority);
+                },
+            )
+            .build()?;
         module
             .function("remove_frame_update_callback", |identifier: &str| {
                 script_core.remove_frame_update_callback(identifier);
@@ -48,13 +48,68 @@ impl StringUtils {
         Some(bytes)
     }
 
+    /// Converts an IDA-style pattern like `"48 8B ?? 89"` into its bytes and mask.
+    /// `?` and `??` are wildcards, which have their entry in the mask set to `false`.
+    /// Spaces between bytes are optional, as long as wildcards are written as `??`.
+    pub fn ida_pattern_to_bytes(pattern: &str) -> Option<(Vec<u8>, Vec<bool>)> {
+        let mut bytes = Vec::new();
+        let mut mask = Vec::new();
+
+        for token in pattern.split_whitespace() {
+            if token == "?" {
+                bytes.push(0);
+                mask.push(false);
+                continue;
+            }
+
+            if token.len() % 2 != 0 {
+                log!(
+                    "[ERROR] Pattern byte \"",
+                    token,
+                    "\" has an uneven length, pattern failing: \"",
+                    pattern,
+                    "\""
+                );
+                return None;
+            }
+
+            for i in (0..token.len()).step_by(2) {
+                let Some(hex) = token.get(i..i + 2) else {
+                    log!("[ERROR] Pattern byte \"", token, "\" isn't valid ASCII!");
+                    return None;
+                };
+
+                if hex == "??" {
+                    bytes.push(0);
+                    mask.push(false);
+                    continue;
+                }
+
+                let Ok(byte) = u8::from_str_radix(hex, 16) else {
+                    log!("[ERROR] \"", hex, "\" cannot be turned into a byte!");
+                    return None;
+                };
+
+                bytes.push(byte);
+                mask.push(true);
+            }
+        }
+
+        Some((bytes, mask))
+    }
+
+    /// Truncates `text` so that it's no longer than `max_len` characters.
+    pub fn truncate_to_chars(text: &mut String, max_len: usize) {
+        if let Some((index, _)) = text.char_indices().nth(max_len) {
+            text.truncate(index);
+        }
+    }
+
     /// Helper for `crash!()` with multiple parameters.
     /// Takes the mutable ZString message and appends `encrypted_arg` at the back of it, reducing
     /// the need for 3 lines of extra code for each additional parameter, down to 1.
     #[inline(never)]
     pub fn crash_helper_append(message: &mut ZString, encrypted_param: impl Display) {
-        let param_content = ZString::new(encrypted_param.to_string());
-        print!("{param_content}");
-        message.push_zstring(param_content);
+        message.push_zstring(ZString::new(encrypted_param.to_string()));
     }
 }
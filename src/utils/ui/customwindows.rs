@@ -1,4 +1,4 @@
-use crate::globals::CONTEXT_PTR;
+use crate::globals::{CONTEXT_PTR, DELTA_TIME, GIF_FPS};
 use crate::mod_cores::base_core::BaseCore;
 use crate::utils::{
     config::Config,
@@ -9,11 +9,13 @@ use crate::utils::{
 };
 use atomic_refcell::AtomicRefCell;
 use dashmap::DashMap;
-use hudhook::imgui::{self, Condition, StyleColor, TextureId, TreeNodeFlags};
+use hudhook::imgui::{
+    self, Condition, ListBox, ProgressBar, StyleColor, TableFlags, TextureId, TreeNodeFlags,
+};
 use indexmap::IndexMap;
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
 use rune::{alloc::clone::TryClone, runtime::SyncFunction, Value};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::{cell::Cell, rc::Rc, sync::atomic::Ordering, sync::Arc};
 use windows::Win32::Foundation::POINT;
 use zstring::ZString;
@@ -36,6 +38,24 @@ enum CallbackType {
     Checkbox(String, bool, Rc<Option<Value>>),
     InputTextMultiLine(String, String, Rc<Option<Value>>),
     ComboBox(String, usize, Rc<Option<Value>>),
+    RadioGroup(String, usize, Rc<Option<Value>>),
+    ColorPicker(String, [f32; 4], Rc<Option<Value>>),
+    ListBox(String, Vec<i64>, Rc<Option<Value>>),
+    InputText(String, String, Rc<Option<Value>>),
+    Password(String, String, Rc<Option<Value>>),
+}
+
+/// Playback state of an animated GIF inside of an image widget.
+#[derive(Default)]
+struct GifPlaybackState {
+    /// Index of the frame being displayed, starting at 0.
+    current_frame: usize,
+
+    /// Time in seconds since the current frame was first displayed.
+    frame_timer: f32,
+
+    /// Is the playback paused?
+    paused: bool,
 }
 
 /// Custom window utilities for making custom windows easier to use, and supporting multiple
@@ -50,9 +70,54 @@ pub struct CustomWindowsUtils {
     /// Window size constraints.
     window_size_constraints: AtomicRefCell<Vec<Arc<[f32; 4]>>>,
 
+    /// Requested window positions, parallel to `window_size_constraints`.
+    /// Each position is applied once and then cleared, so the window can still be moved freely.
+    window_positions: AtomicRefCell<Vec<Option<[f32; 2]>>>,
+
+    /// Last known on-screen window positions, key being the window title.
+    live_window_positions: DashMap<String, [f32; 2]>,
+
+    /// Open state of windows, key being the window title.
+    /// Windows without an entry are open.
+    window_open_states: DashMap<String, bool>,
+
+    /// Software cursor state of windows, key being the window title.
+    /// Windows without an entry have the software cursor enabled.
+    software_cursor_states: DashMap<String, bool>,
+
+    /// Custom window flags, key being the window title.
+    /// Value is `(no_resize, no_title_bar, no_move)`, windows without an entry use the defaults.
+    window_flags: DashMap<String, (bool, bool, bool)>,
+
+    /// Windows drawn as overlays, key being the window title.
+    /// Overlay windows have no decorations, no background and never capture input.
+    window_overlays: DashMap<String, bool>,
+
+    /// Focus and hover states of windows from the last frame, key being the window title.
+    /// Value is `(focused, hovered)`.
+    window_focus_states: DashMap<String, (bool, bool)>,
+
+    /// Tooltips displayed when hovering widgets, key being the window title and the widget
+    /// identifier.
+    widget_tooltips: AtomicRefCell<HashMap<(String, String), String>>,
+
+    /// Custom text colors for widgets, key being the window title and the widget identifier.
+    widget_text_colors: AtomicRefCell<HashMap<(String, String), [f32; 4]>>,
+
     /// Cached GPU TextureIds, key being the path to the image.
     cached_images: AtomicRefCell<HashMap<String, CustomTexture>>,
 
+    /// Decoded in-memory images waiting to be uploaded to the GPU, key being the synthetic image
+    /// path.
+    pending_memory_images: DashMap<String, image::RgbaImage>,
+
+    /// GPU TextureIds of images which have been removed from the cache.
+    /// The render thread releases their memory and reuses them for the next uploads.
+    freed_texture_ids: Mutex<Vec<TextureId>>,
+
+    /// GIF playback states, key being the window title and the image widget identifier.
+    gif_playback_states: DashMap<(String, String), GifPlaybackState>,
+
     /// Current cursor point.
     /// Allowed to be non-thread-safe as it doesn't matter.
     point: Cell<POINT>,
@@ -60,6 +125,10 @@ pub struct CustomWindowsUtils {
     /// Widgets that should remain hidden.
     hidden_widgets: AtomicRefCell<Vec<String>>,
 
+    /// Widgets that are visible, but greyed-out and non-interactive.
+    /// Key being the window title and the widget identifier.
+    disabled_widgets: AtomicRefCell<HashSet<(String, String)>>,
+
     /// If `Some()`, then adding a new widget will result in it getting added to the defined
     /// sub-widget if present.
     /// If `None`, then it's added onto the UI as-is.
@@ -91,6 +160,11 @@ impl CustomWindowsUtils {
         static DEFAULT_SIZE: [f32; 2] = [600.0, 200.0];
 
         for (index, kv) in self.window_widgets.iter().enumerate() {
+            if !self.is_window_open(kv.key()) {
+                self.store_window_focus_state(kv.key(), (false, false));
+                continue;
+            }
+
             let Ok(window_size_constraints) = self.window_size_constraints.try_borrow() else {
                 return;
             };
@@ -102,20 +176,61 @@ impl CustomWindowsUtils {
             let size_constraints = Arc::clone(size_constraints);
             drop(window_size_constraints);
 
+            let position = self
+                .window_positions
+                .try_borrow_mut()
+                .ok()
+                .and_then(|mut window_positions| window_positions.get_mut(index)?.take());
+
             let custom_window = kv.key();
             let default_style = self.activate_color_preset_for_window(custom_window, config);
-            ui.window(custom_window)
+            let mut window = ui
+                .window(custom_window)
                 .size(DEFAULT_SIZE, Condition::FirstUseEver)
                 .size_constraints(
                     [size_constraints[0], size_constraints[1]],
                     [size_constraints[2], size_constraints[3]],
                 )
-                .collapsed(true, Condition::Once)
-                .build(|| {
-                    self.draw_custom_window(Arc::clone(&base_core), kv.value(), ui);
-                    script_core.call_frame_update_callbacks(Some(custom_window), Some(ui));
+                .collapsed(true, Condition::Once);
+
+            if let Some(position) = position {
+                window = window.position(position, Condition::Always);
+            }
+
+            if let Some(window_flags) = self.window_flags.get(custom_window) {
+                let (no_resize, no_title_bar, no_move) = *window_flags;
+                window = window
+                    .resizable(!no_resize)
+                    .title_bar(!no_title_bar)
+                    .movable(!no_move);
+
+                // Without a title bar, a collapsed window can't ever be expanded again.
+                if no_title_bar {
+                    window = window.collapsed(false, Condition::Always);
+                }
+            }
+
+            let is_overlay = self.is_window_overlay(custom_window);
+            if is_overlay {
+                window = window
+                    .no_decoration()
+                    .no_inputs()
+                    .draw_background(false)
+                    .collapsed(false, Condition::Always);
+            }
+
+            let mut focus_state = (false, false);
+            window.build(|| {
+                focus_state = (ui.is_window_focused(), ui.is_window_hovered());
+                self.store_live_window_position(custom_window, ui.window_pos());
+                self.draw_custom_window(Arc::clone(&base_core), custom_window, kv.value(), ui);
+                script_core.call_frame_update_callbacks(Some(custom_window), Some(ui));
+                if !is_overlay && self.is_software_cursor_enabled(custom_window) {
                     ImGuiUtils::render_software_cursor(ui, &mut self.point.get());
-                });
+                }
+            });
+
+            self.store_window_focus_state(custom_window, focus_state);
 
             if let Some(default_style) = default_style {
                 self.restore_preset_to_default(default_style);
@@ -125,6 +240,28 @@ impl CustomWindowsUtils {
         self.call_pending_callbacks();
     }
 
+    /// Stores the live position of `window`, so that it can be read back by scripts.
+    fn store_live_window_position(&self, window: &str, position: [f32; 2]) {
+        if let Some(mut live_position) = self.live_window_positions.get_mut(window) {
+            *live_position = position;
+            return;
+        }
+
+        self.live_window_positions
+            .insert(window.to_owned(), position);
+    }
+
+    /// Stores the focus and hover state of `window`, so that it can be read back by scripts.
+    fn store_window_focus_state(&self, window: &str, focus_state: (bool, bool)) {
+        if let Some(mut last_focus_state) = self.window_focus_states.get_mut(window) {
+            *last_focus_state = focus_state;
+            return;
+        }
+
+        self.window_focus_states
+            .insert(window.to_owned(), focus_state);
+    }
+
     /// Activates the UI Color preset from `window`, if any.
     /// Return value is the default preset if the style was changed, use `restore_preset_to_default` on it after rendering the window.
     fn activate_color_preset_for_window(
@@ -258,6 +395,51 @@ impl CustomWindowsUtils {
                         identifier,
                     )
                 }
+                CallbackType::RadioGroup(identifier, current_value, opt_param) => {
+                    Self::call_callback::<usize>(
+                        callback,
+                        Some(*current_value),
+                        opt_param,
+                        zencstr!("Radio Group"),
+                        identifier,
+                    )
+                }
+                CallbackType::ColorPicker(identifier, color, opt_param) => {
+                    Self::call_callback::<Vec<f32>>(
+                        callback,
+                        Some(color.to_vec()),
+                        opt_param,
+                        zencstr!("Color Picker"),
+                        identifier,
+                    )
+                }
+                CallbackType::ListBox(identifier, selected_indices, opt_param) => {
+                    Self::call_callback::<Vec<i64>>(
+                        callback,
+                        Some(selected_indices.to_owned()),
+                        opt_param,
+                        zencstr!("List Box"),
+                        identifier,
+                    )
+                }
+                CallbackType::InputText(identifier, current_value, opt_param) => {
+                    Self::call_callback::<&str>(
+                        callback,
+                        Some(current_value),
+                        opt_param,
+                        zencstr!("Input Text"),
+                        identifier,
+                    )
+                }
+                CallbackType::Password(identifier, current_value, opt_param) => {
+                    Self::call_callback::<&str>(
+                        callback,
+                        Some(current_value),
+                        opt_param,
+                        zencstr!("Password Input"),
+                        identifier,
+                    )
+                }
                 _ => crash!("[ERROR] Invalid callback type!"),
             }
         }
@@ -269,6 +451,7 @@ impl CustomWindowsUtils {
     fn draw_custom_window(
         &self,
         base_core: Arc<RwLock<BaseCore>>,
+        window: &str,
         widgets: &WidgetsMap,
         ui: &imgui::Ui,
     ) {
@@ -282,7 +465,7 @@ impl CustomWindowsUtils {
             }
 
             drop(hidden_widgets);
-            self.handle_widget(Arc::clone(&base_core), ui, identifier, widget);
+            self.handle_widget(Arc::clone(&base_core), ui, window, identifier, widget);
         }
     }
 
@@ -291,6 +474,7 @@ impl CustomWindowsUtils {
         &self,
         base_core: Arc<RwLock<BaseCore>>,
         ui: &imgui::Ui,
+        window: &str,
         identifier: &str,
         widget: &AtomicRefCell<WidgetType>,
     ) {
@@ -303,7 +487,75 @@ impl CustomWindowsUtils {
             return;
         };
 
-        match &mut *widget {
+        let disabled = self
+            .is_widget_disabled(window, identifier)
+            .then(|| ui.begin_disabled(true));
+        if let WidgetType::SubWidget(sub_widget, widgets, ..) = &mut *widget {
+            self.handle_sub_widget(ui, base_core, window, identifier, sub_widget, widgets);
+        } else {
+            let text_color = self
+                .get_widget_text_color(window, identifier)
+                .map(|text_color| ui.push_style_color(StyleColor::Text, text_color));
+            self.draw_widget(base_core, ui, window, identifier, &mut widget);
+            if let Some(text_color) = text_color {
+                text_color.pop();
+            }
+
+            self.draw_widget_tooltip(ui, window, identifier);
+        }
+
+        if let Some(disabled) = disabled {
+            disabled.end();
+        }
+    }
+
+    /// Checks if `identifier` inside of `window` has been disabled through
+    /// `set_widgets_disabled`.
+    fn is_widget_disabled(&self, window: &str, identifier: &str) -> bool {
+        self.disabled_widgets
+            .try_borrow()
+            .is_ok_and(|disabled_widgets| {
+                !disabled_widgets.is_empty()
+                    && disabled_widgets.contains(&(window.to_owned(), identifier.to_owned()))
+            })
+    }
+
+    /// Gets the custom text color for `identifier` inside of `window`, if any.
+    fn get_widget_text_color(&self, window: &str, identifier: &str) -> Option<[f32; 4]> {
+        let widget_text_colors = self.widget_text_colors.try_borrow().ok()?;
+        if widget_text_colors.is_empty() {
+            return None;
+        }
+
+        widget_text_colors
+            .get(&(window.to_owned(), identifier.to_owned()))
+            .copied()
+    }
+
+    /// Draws the tooltip registered for `identifier` if the last drawn item is hovered.
+    fn draw_widget_tooltip(&self, ui: &imgui::Ui, window: &str, identifier: &str) {
+        let Ok(widget_tooltips) = self.widget_tooltips.try_borrow() else {
+            return;
+        };
+
+        if !widget_tooltips.is_empty()
+            && ui.is_item_hovered()
+            && let Some(tooltip) = widget_tooltips.get(&(window.to_owned(), identifier.to_owned()))
+        {
+            ui.tooltip_text(tooltip);
+        }
+    }
+
+    /// Draws a single non-sub-widget.
+    fn draw_widget(
+        &self,
+        base_core: Arc<RwLock<BaseCore>>,
+        ui: &imgui::Ui,
+        window: &str,
+        identifier: &str,
+        widget: &mut WidgetType,
+    ) {
+        match widget {
             WidgetType::Label(content, font_id) => {
                 let Some(font_token) = ImGuiUtils::activate_font(ui, *font_id) else {
                     log!(
@@ -317,6 +569,27 @@ impl CustomWindowsUtils {
                 label!(ui, content);
                 font_token.pop();
             }
+            WidgetType::LabelWrapped(content, wrap_width, font_id) => {
+                let Some(font_token) = ImGuiUtils::activate_font(ui, *font_id) else {
+                    log!(
+                        "[ERROR] Failed activating non-installed font at index ",
+                        font_id,
+                        "!"
+                    );
+                    return;
+                };
+
+                let wrap_pos = if *wrap_width > 0.0 {
+                    ui.cursor_pos()[0] + *wrap_width
+                } else {
+                    0.0
+                };
+
+                let wrap_token = ui.push_text_wrap_pos_with_pos(wrap_pos);
+                ui.text_wrapped(content);
+                wrap_token.pop();
+                font_token.pop();
+            }
             WidgetType::LabelCustomFont(content, relative_font_path) => {
                 let Some(base_core_reader) = base_core.try_read() else {
                     return;
@@ -343,8 +616,16 @@ impl CustomWindowsUtils {
                 font_token.pop();
             }
             WidgetType::Button(text, callback, opt_param) => {
-                if button!(ui, text) {
+                // Buttons are forced to use black text, unless a custom color has been set.
+                let clicked = if self.get_widget_text_color(window, identifier).is_some() {
+                    ui.button(text)
+                } else {
+                    button!(ui, text)
+                };
+
+                if clicked {
                     self.add_callback(
+                        window,
                         identifier,
                         callback,
                         CallbackType::Button(identifier.to_owned(), Rc::clone(opt_param)),
@@ -356,6 +637,7 @@ impl CustomWindowsUtils {
             WidgetType::F32Slider(text, min, max, current_value, callback, opt_param) => {
                 if slider!(ui, text, *min, *max, *current_value) {
                     self.add_callback(
+                        window,
                         identifier,
                         callback,
                         CallbackType::F32Slider(
@@ -369,6 +651,7 @@ impl CustomWindowsUtils {
             WidgetType::I32Slider(text, min, max, current_value, callback, opt_param) => {
                 if slider!(ui, text, *min, *max, *current_value) {
                     self.add_callback(
+                        window,
                         identifier,
                         callback,
                         CallbackType::I32Slider(
@@ -391,13 +674,19 @@ impl CustomWindowsUtils {
                 opt_param,
                 requested_texture_id,
             ) => {
-                let Some(texture_id) =
-                    self.get_texture_id(image_path, base_core.read().get_config().get_path())
-                else {
+                let config_dir_path = base_core.read().get_config().get_path();
+                let Some(texture_id) = self.get_texture_id(image_path, config_dir_path) else {
                     *requested_texture_id = true;
                     return;
                 };
 
+                let texture_id = if image_path.ends_with(&zencstr!(".gif").data) {
+                    self.get_gif_frame_texture_id(window, identifier, image_path, config_dir_path)
+                        .unwrap_or(texture_id)
+                } else {
+                    texture_id
+                };
+
                 if *overlay {
                     let window_pos = ui.window_pos();
                     ui.get_foreground_draw_list()
@@ -424,6 +713,7 @@ impl CustomWindowsUtils {
 
                 if ImGuiUtils::draw_image(ui, identifier, *width, *height, texture_id) {
                     self.add_callback(
+                        window,
                         identifier,
                         callback,
                         CallbackType::Button(identifier.to_owned(), Rc::clone(opt_param)),
@@ -443,6 +733,7 @@ impl CustomWindowsUtils {
                     .build()
                 {
                     self.add_callback(
+                        window,
                         identifier,
                         callback,
                         CallbackType::InputTextMultiLine(
@@ -453,12 +744,13 @@ impl CustomWindowsUtils {
                     );
                 }
             }
-            WidgetType::SubWidget(sub_widget, widgets, ..) => {
-                self.handle_sub_widget(ui, Arc::clone(&base_core), sub_widget, widgets);
+            WidgetType::SubWidget(..) => {
+                crash!("[ERROR] Sub-widgets must be handled through handle_sub_widget!")
             }
             WidgetType::Checkbox(text, checked, callback, opt_param) => {
                 if ui.checkbox(text, checked) {
                     self.add_callback(
+                        window,
                         identifier,
                         callback,
                         CallbackType::Checkbox(
@@ -472,6 +764,7 @@ impl CustomWindowsUtils {
             WidgetType::ComboBox(text, current_item, items, callback, opt_param) => {
                 if ui.combo_simple_string(text, current_item, items) {
                     self.add_callback(
+                        window,
                         identifier,
                         callback,
                         CallbackType::ComboBox(
@@ -482,6 +775,159 @@ impl CustomWindowsUtils {
                     );
                 }
             }
+            WidgetType::RadioGroup(text, current_item, items, callback, opt_param) => {
+                if items.is_empty() {
+                    return;
+                }
+
+                // Items may have been replaced with fewer entries, keep the selection in range.
+                if *current_item >= items.len() {
+                    *current_item = items.len() - 1;
+                }
+
+                label!(ui, text);
+                let id_token = ui.push_id(identifier);
+                let last_item = *current_item;
+                for (index, item) in items.iter().enumerate() {
+                    ui.radio_button(item, current_item, index);
+                }
+
+                id_token.pop();
+                if *current_item != last_item {
+                    self.add_callback(
+                        window,
+                        identifier,
+                        callback,
+                        CallbackType::RadioGroup(
+                            identifier.to_owned(),
+                            *current_item,
+                            Rc::clone(opt_param),
+                        ),
+                    );
+                }
+            }
+            WidgetType::ProgressBar(fraction, overlay_text) => {
+                let progress_bar = ProgressBar::new(*fraction);
+                if let Some(overlay_text) = overlay_text {
+                    progress_bar.overlay_text(overlay_text).build(ui);
+                } else {
+                    progress_bar.build(ui);
+                }
+            }
+            WidgetType::ListBox(text, selection, items, callback, opt_param) => {
+                // Items may have been replaced, keep the selection mask in sync with them.
+                selection.resize(items.len(), false);
+
+                let mut changed = false;
+                ListBox::new(text).build(ui, || {
+                    if items.is_empty() {
+                        ui.text_disabled(zencstr!("No items"));
+                        return;
+                    }
+
+                    for (index, (item, selected)) in
+                        items.iter().zip(selection.iter_mut()).enumerate()
+                    {
+                        let id_token = ui.push_id_usize(index);
+                        if ui.selectable_config(item).selected(*selected).build() {
+                            *selected = !*selected;
+                            changed = true;
+                        }
+
+                        id_token.pop();
+                    }
+                });
+
+                if changed {
+                    self.add_callback(
+                        window,
+                        identifier,
+                        callback,
+                        CallbackType::ListBox(
+                            identifier.to_owned(),
+                            Self::get_selected_indices(selection),
+                            Rc::clone(opt_param),
+                        ),
+                    );
+                }
+            }
+            WidgetType::InputText(label, text_input, max_len, callback, opt_param) => {
+                if ui.input_text(label, text_input).build() {
+                    StringUtils::truncate_to_chars(text_input, *max_len);
+                    self.add_callback(
+                        window,
+                        identifier,
+                        callback,
+                        CallbackType::InputText(
+                            identifier.to_owned(),
+                            text_input.to_owned(),
+                            Rc::clone(opt_param),
+                        ),
+                    );
+                }
+            }
+            WidgetType::Password(label, text_input, callback, opt_param) => {
+                if ui.input_text(label, text_input).password(true).build() {
+                    self.add_callback(
+                        window,
+                        identifier,
+                        callback,
+                        CallbackType::Password(
+                            identifier.to_owned(),
+                            text_input.to_owned(),
+                            Rc::clone(opt_param),
+                        ),
+                    );
+                }
+            }
+            WidgetType::PlotLines(label, values, scale_min, scale_max) => ui
+                .plot_lines(label, values)
+                .scale_min(*scale_min)
+                .scale_max(*scale_max)
+                .build(),
+            WidgetType::LogPanel(lines, height) => {
+                ImGuiUtils::draw_log_panel(ui, identifier, lines, *height)
+            }
+            WidgetType::Table(headers, rows) => {
+                let Some(table_token) = ui.begin_table_with_flags(
+                    identifier,
+                    headers.len(),
+                    TableFlags::BORDERS | TableFlags::ROW_BG | TableFlags::RESIZABLE,
+                ) else {
+                    return;
+                };
+
+                for header in headers.iter() {
+                    ui.table_setup_column(header);
+                }
+
+                ui.table_headers_row();
+                for row in rows.iter() {
+                    ui.table_next_row();
+
+                    // Rows may be shorter or longer than the amount of columns.
+                    for cell in row.iter().take(headers.len()) {
+                        ui.table_next_column();
+                        ui.text(cell);
+                    }
+                }
+
+                table_token.end();
+            }
+            WidgetType::ColorPicker(text, color, alpha, callback, opt_param) => {
+                if ui.color_edit4_config(text, color).alpha(*alpha).build() {
+                    self.add_callback(
+                        window,
+                        identifier,
+                        callback,
+                        CallbackType::ColorPicker(
+                            identifier.to_owned(),
+                            *color,
+                            Rc::clone(opt_param),
+                        ),
+                    );
+                }
+            }
         }
     }
 
@@ -490,6 +936,8 @@ impl CustomWindowsUtils {
         &self,
         ui: &imgui::Ui,
         base_core: Arc<RwLock<BaseCore>>,
+        window: &str,
+        identifier: &str,
         sub_widget: &mut SubWidgetType,
         widgets: &WidgetsMap,
     ) {
@@ -503,16 +951,44 @@ impl CustomWindowsUtils {
             .filter(|(identifier, _)| !hidden_widgets.contains(identifier));
         match sub_widget {
             SubWidgetType::CollapsingHeader(text) => {
-                let text_color = ui.push_style_color(StyleColor::Text, [0.0, 0.0, 0.0, 1.0]);
-                if !ui.collapsing_header(text, TreeNodeFlags::OPEN_ON_ARROW) {
-                    text_color.pop();
+                let text_color = ui.push_style_color(
+                    StyleColor::Text,
+                    self.get_widget_text_color(window, identifier)
+                        .unwrap_or([0.0, 0.0, 0.0, 1.0]),
+                );
+                let is_open = ui.collapsing_header(text, TreeNodeFlags::OPEN_ON_ARROW);
+                text_color.pop();
+                self.draw_widget_tooltip(ui, window, identifier);
+                if !is_open {
                     return;
                 }
 
-                text_color.pop();
                 for (identifier, widget) in widgets {
-                    self.handle_widget(Arc::clone(&base_core), ui, identifier, widget);
+                    self.handle_widget(Arc::clone(&base_core), ui, window, identifier, widget);
+                }
+            }
+            SubWidgetType::TabBar => {
+                let Some(tab_bar) = ui.tab_bar(identifier) else {
+                    return;
+                };
+
+                for (identifier, widget) in widgets {
+                    self.handle_widget(Arc::clone(&base_core), ui, window, identifier, widget);
+                }
+
+                tab_bar.end();
+            }
+            SubWidgetType::TabItem(text) => {
+                let Some(tab_item) = ui.tab_item(text) else {
+                    return;
+                };
+
+                self.draw_widget_tooltip(ui, window, identifier);
+                for (identifier, widget) in widgets {
+                    self.handle_widget(Arc::clone(&base_core), ui, window, identifier, widget);
                 }
+
+                tab_item.end();
             }
         }
     }
@@ -520,10 +996,17 @@ impl CustomWindowsUtils {
     /// Adds a callback to `self.pending_callbacks`.
     fn add_callback(
         &self,
+        window: &str,
         identifier: &str,
         callback: &Rc<SyncFunction>,
         callback_type: CallbackType,
     ) {
+        // Disabled widgets should never be able to queue callbacks, even if imgui somehow
+        // reported an interaction.
+        if self.is_widget_disabled(window, identifier) {
+            return;
+        }
+
         let Ok(mut pending_callbacks) = self.pending_callbacks.try_borrow_mut() else {
             log!("[ERROR] Pending callbacks is in use and cannot be accessed!");
             return;
@@ -555,7 +1038,13 @@ impl CustomWindowsUtils {
             return;
         };
 
+        let Ok(mut window_positions) = self.window_positions.try_borrow_mut() else {
+            log!("[ERROR] Tried to add window when window positions is locked and in use, cancelled!");
+            return;
+        };
+
         window_size_constraints.push(Arc::new([0.0, 0.0, 9999.0, 9999.0]));
+        window_positions.push(None);
         self.window_widgets.insert(title, Default::default());
     }
 
@@ -589,8 +1078,34 @@ impl CustomWindowsUtils {
             return;
         };
 
+        let Ok(mut window_positions) = self.window_positions.try_borrow_mut() else {
+            log!("[ERROR] Window positions is locked, cannot access as mutable!");
+            return;
+        };
+
         self.window_widgets.remove(&window);
+        if let Ok(mut widget_tooltips) = self.widget_tooltips.try_borrow_mut() {
+            widget_tooltips.retain(|(tooltip_window, _), _| *tooltip_window != window);
+        }
+
+        if let Ok(mut widget_text_colors) = self.widget_text_colors.try_borrow_mut() {
+            widget_text_colors.retain(|(color_window, _), _| *color_window != window);
+        }
+
+        if let Ok(mut disabled_widgets) = self.disabled_widgets.try_borrow_mut() {
+            disabled_widgets.retain(|(disabled_window, _)| *disabled_window != window);
+        }
+
+        self.live_window_positions.remove(&window);
+        self.window_open_states.remove(&window);
+        self.software_cursor_states.remove(&window);
+        self.window_flags.remove(&window);
+        self.window_overlays.remove(&window);
+        self.window_focus_states.remove(&window);
+        self.gif_playback_states
+            .retain(|(gif_window, _), _| *gif_window != window);
         window_size_constraints.remove(index);
+        window_positions.remove(index);
     }
 
     /// Adds a widget to the currently selected custom window.
@@ -611,16 +1126,6 @@ impl CustomWindowsUtils {
             return;
         };
 
-        if let Some(parent_identifier) = self.add_into_sub_widget.borrow().as_ref() {
-            self.add_into_sub_widget(
-                &mut window_widgets,
-                parent_identifier,
-                identifier,
-                widget_type,
-            );
-            return;
-        }
-
         let sub_widget_data =
             if let WidgetType::SubWidget(_, _, ref call_once, ref opt_param) = widget_type {
                 Some((Rc::clone(call_once), Rc::clone(opt_param)))
@@ -628,9 +1133,20 @@ impl CustomWindowsUtils {
                 None
             };
 
-        #[allow(clippy::arc_with_non_send_sync)]
-        let widget = Arc::new(AtomicRefCell::new(widget_type));
-        window_widgets.insert(identifier.to_owned(), Arc::clone(&widget));
+        let parent_identifier = self.add_into_sub_widget.borrow().to_owned();
+        if let Some(parent_identifier) = &parent_identifier {
+            self.add_into_sub_widget(
+                &window_widgets,
+                parent_identifier,
+                identifier.to_owned(),
+                widget_type,
+            );
+        } else {
+            #[allow(clippy::arc_with_non_send_sync)]
+            let widget = Arc::new(AtomicRefCell::new(widget_type));
+            window_widgets.insert(identifier.to_owned(), widget);
+        }
+
         drop(window_widgets);
 
         // If the added widget was a sub-widget, call the function attached.
@@ -643,7 +1159,7 @@ impl CustomWindowsUtils {
             .call::<(Option<&Value>,), ()>((opt_param.as_ref().as_ref(),))
             .into_result()
         else {
-            self.set_sub_widget_identifier(None);
+            self.set_sub_widget_identifier(parent_identifier);
             return;
         };
 
@@ -653,13 +1169,14 @@ impl CustomWindowsUtils {
             "\", error: ",
             error
         );
-        self.set_sub_widget_identifier(None);
+        self.set_sub_widget_identifier(parent_identifier);
     }
 
     /// Adds `widget_type` into the sub-widget found by `sub_widget_identifier` if found.
+    /// Nested sub-widgets are searched depth-first.
     fn add_into_sub_widget(
         &self,
-        widgets: &mut WidgetsMap,
+        widgets: &WidgetsMap,
         sub_widget_identifier: &str,
         mut identifier: String,
         widget_type: WidgetType,
@@ -671,11 +1188,7 @@ impl CustomWindowsUtils {
             identifier = StringUtils::get_random();
         }
 
-        let Some(sub_widget) = widgets
-            .iter()
-            .find(|(identifier, _)| *identifier == sub_widget_identifier)
-            .map(|(_, widget)| widget)
-        else {
+        let Some(sub_widget) = Self::find_widget_in(widgets, sub_widget_identifier) else {
             log!("[ERROR] No widgets named \"", sub_widget_identifier, "\"!");
             return;
         };
@@ -702,12 +1215,44 @@ impl CustomWindowsUtils {
         widgets.insert(identifier, Arc::new(AtomicRefCell::new(widget_type)));
     }
 
-    /// Removes a widget from the currently selected custom window.
-    pub fn remove_widget(&self, window: &str, identifier: String) {
-        let window_widgets = self.window_widgets.try_get_mut(window);
-        if window_widgets.is_locked() {
-            log!(
-                "[ERROR] Window widgets is locked, no widgets can be removed from \"",
+    /// Finds the widget named `identifier` inside of `widgets`, searching sub-widgets
+    /// depth-first.
+    fn find_widget_in(
+        widgets: &WidgetsMap,
+        identifier: &str,
+    ) -> Option<Arc<AtomicRefCell<WidgetType>>> {
+        if let Some(widget) = widgets.get(identifier) {
+            return Some(Arc::clone(widget));
+        }
+
+        for (widget_identifier, widget) in widgets {
+            let Ok(widget) = widget.try_borrow() else {
+                log!(
+                    "[ERROR] Failed to borrow widget \"",
+                    widget_identifier,
+                    "\", cannot search inside of it!"
+                );
+                continue;
+            };
+
+            let WidgetType::SubWidget(_, widgets, ..) = &*widget else {
+                continue;
+            };
+
+            if let Some(widget) = Self::find_widget_in(widgets, identifier) {
+                return Some(widget);
+            }
+        }
+
+        None
+    }
+
+    /// Removes a widget from the currently selected custom window.
+    pub fn remove_widget(&self, window: &str, identifier: String) {
+        let window_widgets = self.window_widgets.try_get_mut(window);
+        if window_widgets.is_locked() {
+            log!(
+                "[ERROR] Window widgets is locked, no widgets can be removed from \"",
                 window,
                 "\"!"
             );
@@ -719,15 +1264,35 @@ impl CustomWindowsUtils {
         };
 
         window_widgets.shift_remove(&identifier);
+        self.gif_playback_states
+            .remove(&(window.to_owned(), identifier.to_owned()));
+        if let Ok(mut widget_tooltips) = self.widget_tooltips.try_borrow_mut() {
+            widget_tooltips.remove(&(window.to_owned(), identifier.to_owned()));
+        }
+
+        if let Ok(mut widget_text_colors) = self.widget_text_colors.try_borrow_mut() {
+            widget_text_colors.remove(&(window.to_owned(), identifier.to_owned()));
+        }
+
+        if let Ok(mut disabled_widgets) = self.disabled_widgets.try_borrow_mut() {
+            disabled_widgets.remove(&(window.to_owned(), identifier.to_owned()));
+        }
 
         // Iterate over sub-widgets and remove any potential matches.
-        for (_, widget) in &*window_widgets {
+        Self::remove_widget_in(&window_widgets, &identifier);
+    }
+
+    /// Removes the widget named `identifier` from all sub-widgets inside of `widgets`,
+    /// searching nested sub-widgets depth-first.
+    fn remove_widget_in(widgets: &WidgetsMap, identifier: &str) {
+        for (_, widget) in widgets {
             let Ok(mut widget) = widget.try_borrow_mut() else {
                 continue;
             };
 
             if let WidgetType::SubWidget(_, widgets, ..) = &mut *widget {
-                widgets.shift_remove(&identifier);
+                widgets.shift_remove(identifier);
+                Self::remove_widget_in(widgets, identifier);
             }
         }
     }
@@ -749,6 +1314,19 @@ impl CustomWindowsUtils {
         };
 
         window_widgets.clear();
+        self.gif_playback_states
+            .retain(|(gif_window, _), _| gif_window != window);
+        if let Ok(mut widget_tooltips) = self.widget_tooltips.try_borrow_mut() {
+            widget_tooltips.retain(|(tooltip_window, _), _| tooltip_window != window);
+        }
+
+        if let Ok(mut widget_text_colors) = self.widget_text_colors.try_borrow_mut() {
+            widget_text_colors.retain(|(color_window, _), _| color_window != window);
+        }
+
+        if let Ok(mut disabled_widgets) = self.disabled_widgets.try_borrow_mut() {
+            disabled_widgets.retain(|(disabled_window, _)| disabled_window != window);
+        }
     }
 
     /// Gets a widget from a specific window.
@@ -768,42 +1346,15 @@ impl CustomWindowsUtils {
         }
 
         let window_widgets = window_widgets.try_unwrap()?;
-        for (widget_identifier, widget) in &*window_widgets {
-            // If widget was found outside of a `CenteredWidget` widget, return it.
-            if widget_identifier == identifier {
-                return Some(Arc::clone(widget));
-            }
-
-            let Ok(widget) = widget.try_borrow() else {
-                log!(
-                    "[ERROR] Failed to borrow widget \"",
-                    widget_identifier,
-                    "\", cannot safely return widget!"
-                );
-                return None;
-            };
-
-            // Otherwise, check if the widget is CenteredWidgets and scan the widgets inside of
-            // it. If found, return it.
-            // This won't work with nested CenteredWidgets, but that's frowned upon and
-            // shouldn't be accounted for regardless.
-            let WidgetType::SubWidget(_, widgets, ..) = &*widget else {
-                continue;
-            };
-
-            let Some(widget) = widgets.get(identifier) else {
-                continue;
-            };
-
-            return Some(Arc::clone(widget));
+        if let Some(widget) = Self::find_widget_in(&window_widgets, identifier) {
+            return Some(widget);
         }
 
         log!("[ERROR] There is no widget named \"", identifier, "\"!");
         None
     }
 
-    /// Tries to update the text of an existing label.
-    /// TODO: Make work on any widget with a label.
+    /// Tries to update the text of an existing label, or the label of any widget which has one.
     pub fn update_label(&self, window: &str, identifier: String, new_text: String) {
         let Some(widget) = self.get_widget(window, &identifier) else {
             log!("[ERROR] There are no widgets named \"", identifier, "\"!");
@@ -822,14 +1373,177 @@ impl CustomWindowsUtils {
         }
 
         let mut widget = widget.unwrap();
-        if let WidgetType::Label(text, _) = &mut *widget {
-            text.data = new_text;
+        match &mut *widget {
+            WidgetType::Label(text, _)
+            | WidgetType::LabelWrapped(text, ..)
+            | WidgetType::Button(text, ..)
+            | WidgetType::Checkbox(text, ..)
+            | WidgetType::F32Slider(text, ..)
+            | WidgetType::I32Slider(text, ..)
+            | WidgetType::ComboBox(text, ..)
+            | WidgetType::RadioGroup(text, ..)
+            | WidgetType::InputTextMultiLine(text, ..)
+            | WidgetType::InputText(text, ..)
+            | WidgetType::Password(text, ..)
+            | WidgetType::ColorPicker(text, ..)
+            | WidgetType::ListBox(text, ..)
+            | WidgetType::PlotLines(text, ..) => text.data = new_text,
+            WidgetType::LabelCustomFont(text, _) => *text = new_text,
+            _ => log!(
+                "[ERROR] Widget \"",
+                identifier,
+                "\" has no label, cannot update it!"
+            ),
+        }
+    }
+
+    /// Tries to update the fraction of an existing progress bar, clamped between `0.0` and `1.0`.
+    pub fn update_progress_bar(&self, window: &str, identifier: String, new_fraction: f32) {
+        let Some(widget) = self.get_widget(window, &identifier) else {
+            return;
+        };
+
+        let Ok(mut widget) = widget.try_borrow_mut() else {
+            log!(
+                "[ERROR] Failed mutably borrowing widget \"",
+                identifier,
+                "\", cannot update progress bar!"
+            );
+            return;
+        };
+
+        let WidgetType::ProgressBar(fraction, _) = &mut *widget else {
+            log!(
+                "[ERROR] Widget \"",
+                identifier,
+                "\" is not a progress bar, cannot update fraction!"
+            );
+            return;
+        };
+
+        *fraction = new_fraction.clamp(0.0, 1.0);
+    }
+
+    /// Pushes `value` into an existing plot, dropping the oldest value once it holds
+    /// `MAX_PLOT_VALUES` values.
+    pub fn push_plot_value(&self, window: &str, identifier: String, value: f32) {
+        const MAX_PLOT_VALUES: usize = 256;
+
+        let Some(widget) = self.get_widget(window, &identifier) else {
+            return;
+        };
+
+        let Ok(mut widget) = widget.try_borrow_mut() else {
+            log!(
+                "[ERROR] Failed mutably borrowing widget \"",
+                identifier,
+                "\", cannot push plot value!"
+            );
             return;
+        };
+
+        let WidgetType::PlotLines(_, values, ..) = &mut *widget else {
+            log!(
+                "[ERROR] Widget \"",
+                identifier,
+                "\" is not a plot, cannot push value!"
+            );
+            return;
+        };
+
+        if values.len() >= MAX_PLOT_VALUES {
+            values.drain(..=values.len() - MAX_PLOT_VALUES);
         }
 
-        if let WidgetType::LabelCustomFont(text, _) = &mut *widget {
-            *text = new_text;
+        values.push(value);
+    }
+
+    /// Pushes `line` into an existing log panel, dropping the oldest line once it holds
+    /// `MAX_LOG_PANEL_LINES` lines.
+    pub fn push_log_panel_line(&self, window: &str, identifier: String, line: String) {
+        const MAX_LOG_PANEL_LINES: usize = 500;
+
+        let Some(widget) = self.get_widget(window, &identifier) else {
+            return;
+        };
+
+        let Ok(mut widget) = widget.try_borrow_mut() else {
+            log!(
+                "[ERROR] Failed mutably borrowing widget \"",
+                identifier,
+                "\", cannot push log panel line!"
+            );
+            return;
+        };
+
+        let WidgetType::LogPanel(lines, _) = &mut *widget else {
+            log!(
+                "[ERROR] Widget \"",
+                identifier,
+                "\" is not a log panel, cannot push line!"
+            );
+            return;
+        };
+
+        if lines.len() >= MAX_LOG_PANEL_LINES {
+            lines.drain(..=lines.len() - MAX_LOG_PANEL_LINES);
         }
+
+        lines.push(line);
+    }
+
+    /// Removes all lines from an existing log panel.
+    pub fn clear_log_panel(&self, window: &str, identifier: String) {
+        let Some(widget) = self.get_widget(window, &identifier) else {
+            return;
+        };
+
+        let Ok(mut widget) = widget.try_borrow_mut() else {
+            log!(
+                "[ERROR] Failed mutably borrowing widget \"",
+                identifier,
+                "\", cannot clear log panel!"
+            );
+            return;
+        };
+
+        let WidgetType::LogPanel(lines, _) = &mut *widget else {
+            log!(
+                "[ERROR] Widget \"",
+                identifier,
+                "\" is not a log panel, cannot clear it!"
+            );
+            return;
+        };
+
+        lines.clear();
+    }
+
+    /// Replaces all rows of an existing table.
+    pub fn set_table_rows(&self, window: &str, identifier: String, new_rows: Vec<Vec<String>>) {
+        let Some(widget) = self.get_widget(window, &identifier) else {
+            return;
+        };
+
+        let Ok(mut widget) = widget.try_borrow_mut() else {
+            log!(
+                "[ERROR] Failed mutably borrowing widget \"",
+                identifier,
+                "\", cannot set table rows!"
+            );
+            return;
+        };
+
+        let WidgetType::Table(_, rows) = &mut *widget else {
+            log!(
+                "[ERROR] Widget \"",
+                identifier,
+                "\" is not a table, cannot set rows!"
+            );
+            return;
+        };
+
+        *rows = new_rows;
     }
 
     /// Attempts to get the value of a f32-slider from the defined window.
@@ -854,6 +1568,131 @@ impl CustomWindowsUtils {
         Some(current_value)
     }
 
+    /// Sets the value of an existing f32-slider, clamped between its min and max values.
+    /// The slider callback isn't called.
+    pub fn set_f32_slider_value(&self, window: &str, identifier: String, new_value: f32) {
+        let Some(widget) = self.get_widget(window, &identifier) else {
+            return;
+        };
+
+        let Ok(mut widget) = widget.try_borrow_mut() else {
+            log!(
+                "[ERROR] Failed mutably borrowing widget \"",
+                identifier,
+                "\", cannot update slider!"
+            );
+            return;
+        };
+
+        let WidgetType::F32Slider(_, min, max, current_value, _, _) = &mut *widget else {
+            log!(
+                "[ERROR] Widget \"",
+                identifier,
+                "\" is not a f32-slider, cannot update value!"
+            );
+            return;
+        };
+
+        // Not using `clamp` as it panics if `min` is greater than `max`.
+        *current_value = new_value.max(*min).min(*max);
+    }
+
+    /// Sets the value of an existing i32-slider, clamped between its min and max values.
+    /// The slider callback isn't called.
+    pub fn set_i32_slider_value(&self, window: &str, identifier: String, new_value: i32) {
+        let Some(widget) = self.get_widget(window, &identifier) else {
+            return;
+        };
+
+        let Ok(mut widget) = widget.try_borrow_mut() else {
+            log!(
+                "[ERROR] Failed mutably borrowing widget \"",
+                identifier,
+                "\", cannot update slider!"
+            );
+            return;
+        };
+
+        let WidgetType::I32Slider(_, min, max, current_value, _, _) = &mut *widget else {
+            log!(
+                "[ERROR] Widget \"",
+                identifier,
+                "\" is not a i32-slider, cannot update value!"
+            );
+            return;
+        };
+
+        // Not using `clamp` as it panics if `min` is greater than `max`.
+        *current_value = new_value.max(*min).min(*max);
+    }
+
+    /// Replaces the items of an existing combobox. If the selected item no longer exists, the
+    /// last item is selected instead.
+    pub fn set_combobox_items(&self, window: &str, identifier: String, new_items: Vec<String>) {
+        let Some(widget) = self.get_widget(window, &identifier) else {
+            return;
+        };
+
+        let Ok(mut widget) = widget.try_borrow_mut() else {
+            log!(
+                "[ERROR] Failed mutably borrowing widget \"",
+                identifier,
+                "\", cannot update combobox!"
+            );
+            return;
+        };
+
+        let WidgetType::ComboBox(_, current_item, items, _, _) = &mut *widget else {
+            log!(
+                "[ERROR] Widget \"",
+                identifier,
+                "\" is not a combobox, cannot update items!"
+            );
+            return;
+        };
+
+        *current_item = (*current_item).min(new_items.len().saturating_sub(1));
+        *items = new_items;
+    }
+
+    /// Attempts to get the checked state of a checkbox from the defined window.
+    pub fn get_checkbox_value(&self, window: &str, identifier: String) -> Option<bool> {
+        let WidgetType::Checkbox(_, checked, _, _) =
+            *self.get_widget(window, &identifier)?.borrow()
+        else {
+            return None;
+        };
+
+        Some(checked)
+    }
+
+    /// Sets the checked state of an existing checkbox, without calling its callback.
+    pub fn set_checkbox_value(&self, window: &str, identifier: String, new_checked: bool) {
+        let Some(widget) = self.get_widget(window, &identifier) else {
+            return;
+        };
+
+        let Ok(mut widget) = widget.try_borrow_mut() else {
+            log!(
+                "[ERROR] Failed mutably borrowing widget \"",
+                identifier,
+                "\", cannot update checkbox!"
+            );
+            return;
+        };
+
+        let WidgetType::Checkbox(_, checked, _, _) = &mut *widget else {
+            log!(
+                "[ERROR] Widget \"",
+                identifier,
+                "\" is not a checkbox, cannot update checked state!"
+            );
+            return;
+        };
+
+        *checked = new_checked;
+    }
+
     /// Attempts to get the value of a i32-slider from the defined window.
     pub fn get_input_text_multiline_value(
         &self,
@@ -868,6 +1707,68 @@ impl CustomWindowsUtils {
         Some(input.to_owned())
     }
 
+    /// Attempts to get the selected index of a radio group from the defined window.
+    pub fn get_radio_group_value(&self, window: &str, identifier: String) -> Option<usize> {
+        let WidgetType::RadioGroup(_, current_item, _, _, _) =
+            *self.get_widget(window, &identifier)?.borrow()
+        else {
+            return None;
+        };
+
+        Some(current_item)
+    }
+
+    /// Attempts to get the value of a single-line input text from the defined window.
+    pub fn get_input_text_value(&self, window: &str, identifier: String) -> Option<String> {
+        let widget = self.get_widget(window, &identifier)?;
+        let WidgetType::InputText(_, input, ..) = &*widget.borrow() else {
+            return None;
+        };
+
+        Some(input.to_owned())
+    }
+
+    /// Attempts to get the value of a password input from the defined window.
+    pub fn get_password_value(&self, window: &str, identifier: String) -> Option<String> {
+        let widget = self.get_widget(window, &identifier)?;
+        let WidgetType::Password(_, input, ..) = &*widget.borrow() else {
+            return None;
+        };
+
+        Some(input.to_owned())
+    }
+
+    /// Attempts to get the RGBA color of a color picker from the defined window.
+    pub fn get_color_picker_value(&self, window: &str, identifier: String) -> Option<Vec<f32>> {
+        let WidgetType::ColorPicker(_, color, _, _, _) =
+            *self.get_widget(window, &identifier)?.borrow()
+        else {
+            return None;
+        };
+
+        Some(color.to_vec())
+    }
+
+    /// Attempts to get the selected indices of a list box from the defined window.
+    pub fn get_list_box_selection(&self, window: &str, identifier: String) -> Option<Vec<i64>> {
+        let widget = self.get_widget(window, &identifier)?;
+        let WidgetType::ListBox(_, selection, ..) = &*widget.borrow() else {
+            return None;
+        };
+
+        Some(Self::get_selected_indices(selection))
+    }
+
+    /// Turns a selection mask into the indices of the selected items.
+    fn get_selected_indices(selection: &[bool]) -> Vec<i64> {
+        selection
+            .iter()
+            .enumerate()
+            .filter(|(_, selected)| **selected)
+            .map(|(index, _)| index as i64)
+            .collect()
+    }
+
     /// Tries to find the index of the defined window.
     fn get_index_for_window(&self, window: &str) -> Option<usize> {
         self.window_widgets.iter().position(|kv| kv.key() == window)
@@ -894,6 +1795,119 @@ impl CustomWindowsUtils {
         }
     }
 
+    /// Moves `window` to `position` on the next frame.
+    pub fn set_window_position(&self, window: &str, position: [f32; 2]) {
+        let Ok(mut window_positions) = self.window_positions.try_borrow_mut() else {
+            log!("[ERROR] Window positions is locked, cannot access as mutable!");
+            return;
+        };
+
+        let Some(index) = self.get_index_for_window(window) else {
+            log!(
+                "[ERROR] Couldn't find the index for window \"",
+                window,
+                "\"!"
+            );
+            return;
+        };
+
+        if let Some(window_position) = window_positions.get_mut(index) {
+            *window_position = Some(position);
+        }
+    }
+
+    /// Gets the last known position of `window`.
+    /// Returns `None` if the window hasn't been drawn yet.
+    pub fn get_window_position(&self, window: &str) -> Option<[f32; 2]> {
+        self.live_window_positions
+            .get(window)
+            .map(|position| *position)
+    }
+
+    /// Opens or closes `window`.
+    /// Closed windows aren't drawn, but keep all of their widgets.
+    pub fn set_window_open(&self, window: &str, open: bool) {
+        if !self.window_widgets.contains_key(window) {
+            log!("[ERROR] No window named \"", window, "\" was found!");
+            return;
+        }
+
+        self.window_open_states.insert(window.to_owned(), open);
+    }
+
+    /// Checks if `window` is open.
+    pub fn is_window_open(&self, window: &str) -> bool {
+        self.window_open_states.get(window).is_none_or(|open| *open)
+    }
+
+    /// Sets the window flags for `window`.
+    pub fn set_window_flags(
+        &self,
+        window: &str,
+        no_resize: bool,
+        no_title_bar: bool,
+        no_move: bool,
+    ) {
+        if !self.window_widgets.contains_key(window) {
+            log!("[ERROR] No window named \"", window, "\" was found!");
+            return;
+        }
+
+        self.window_flags
+            .insert(window.to_owned(), (no_resize, no_title_bar, no_move));
+    }
+
+    /// Turns `window` into an overlay, or back into a regular window.
+    /// Overlay windows have no decorations, no background and never capture input. Use
+    /// `set_window_position` to place them.
+    pub fn set_window_overlay(&self, window: &str, overlay: bool) {
+        if !self.window_widgets.contains_key(window) {
+            log!("[ERROR] No window named \"", window, "\" was found!");
+            return;
+        }
+
+        self.window_overlays.insert(window.to_owned(), overlay);
+    }
+
+    /// Checks if `window` should be drawn as an overlay.
+    fn is_window_overlay(&self, window: &str) -> bool {
+        self.window_overlays
+            .get(window)
+            .is_some_and(|overlay| *overlay)
+    }
+
+    /// Checks if `window` was focused during the last frame.
+    pub fn is_window_focused(&self, window: &str) -> bool {
+        self.window_focus_states
+            .get(window)
+            .is_some_and(|focus_state| focus_state.0)
+    }
+
+    /// Checks if `window` was hovered during the last frame.
+    pub fn is_window_hovered(&self, window: &str) -> bool {
+        self.window_focus_states
+            .get(window)
+            .is_some_and(|focus_state| focus_state.1)
+    }
+
+    /// Enables or disables the software cursor for `window`.
+    pub fn set_software_cursor_enabled(&self, window: &str, enabled: bool) {
+        if !self.window_widgets.contains_key(window) {
+            log!("[ERROR] No window named \"", window, "\" was found!");
+            return;
+        }
+
+        self.software_cursor_states
+            .insert(window.to_owned(), enabled);
+    }
+
+    /// Checks if the software cursor should be drawn for `window`.
+    fn is_software_cursor_enabled(&self, window: &str) -> bool {
+        self.software_cursor_states
+            .get(window)
+            .is_none_or(|enabled| *enabled)
+    }
+
     /// Replaces the image in an existing widget with a new one.
     pub fn replace_image(
         &self,
@@ -943,12 +1957,209 @@ impl CustomWindowsUtils {
             return;
         };
 
-        cached_textures.clear();
+        self.free_texture_ids(cached_textures.drain().map(|(_, texture)| texture));
+        self.pending_memory_images.clear();
+    }
+
+    /// Removes a single cached image.
+    /// For GIFs, all of the cached frames are removed as well.
+    pub fn remove_cached_image(&self, image_path: &str, config_dir_path: &str) {
+        let full_image_path = Self::get_full_image_path(image_path, config_dir_path);
+        let Ok(mut cached_textures) = self.cached_images.try_borrow_mut() else {
+            log!(
+                "[ERROR] Cached textures is in use, cannot remove \"",
+                image_path,
+                "\"!"
+            );
+            return;
+        };
+
+        let Some(removed_texture) = cached_textures.remove(&full_image_path) else {
+            log!(
+                "[ERROR] Image \"",
+                image_path,
+                "\" isn't cached, cannot remove!"
+            );
+            return;
+        };
+
+        if removed_texture.texture_type == CustomTextureType::Gif {
+            let frame_prefix = ozencstr!(full_image_path, ".frame_");
+            let frame_paths: Vec<String> = cached_textures
+                .keys()
+                .filter(|path| path.starts_with(&frame_prefix))
+                .cloned()
+                .collect();
+            let frames = frame_paths
+                .iter()
+                .filter_map(|frame_path| cached_textures.remove(frame_path));
+            self.free_texture_ids(frames);
+        } else {
+            self.free_texture_ids([removed_texture]);
+        }
+
+        self.pending_memory_images.remove(&full_image_path);
+    }
+
+    /// Queues the GPU textures of removed cache entries to be released by the render thread.
+    /// GIF entries are skipped, as they share their TextureId with the first frame.
+    fn free_texture_ids(&self, textures: impl IntoIterator<Item = CustomTexture>) {
+        let mut freed_texture_ids = self.freed_texture_ids.lock();
+        freed_texture_ids.extend(
+            textures
+                .into_iter()
+                .filter(|texture| texture.texture_type != CustomTextureType::Gif)
+                .filter_map(|texture| texture.texture_id),
+        );
+    }
+
+    /// Takes the TextureIds of all images removed since the last call.
+    pub fn take_freed_texture_ids(&self) -> Vec<TextureId> {
+        std::mem::take(&mut *self.freed_texture_ids.lock())
+    }
+
+    /// Gets the natural width and height of an image, if it has been uploaded to the GPU.
+    pub fn get_image_size(&self, image_path: &str, config_dir_path: &str) -> Option<[u32; 2]> {
+        let full_image_path = Self::get_full_image_path(image_path, config_dir_path);
+        let Ok(cached_textures) = self.cached_images.try_borrow() else {
+            log!("[ERROR] Cached textures is locked, cannot get image size!");
+            return None;
+        };
+
+        let cached_texture = cached_textures.get(&full_image_path)?;
+        cached_texture.texture_id.map(|_| cached_texture.size)
+    }
+
+    /// Gets the path `image_path` is cached under.
+    /// In-memory images are cached as-is, everything else is relative to `config_dir_path`.
+    fn get_full_image_path(image_path: &str, config_dir_path: &str) -> String {
+        if image_path.starts_with(&zencstr!("memory://").data) {
+            image_path.to_owned()
+        } else {
+            ozencstr!(config_dir_path, image_path)
+        }
+    }
+
+    /// Advances the GIF playback of the image widget `identifier` inside of `window` and returns
+    /// the Texture ID of the frame that should be displayed.
+    fn get_gif_frame_texture_id(
+        &self,
+        window: &str,
+        identifier: &str,
+        image_path: &str,
+        config_dir_path: &str,
+    ) -> Option<TextureId> {
+        let mut playback_state = self
+            .gif_playback_states
+            .entry((window.to_owned(), identifier.to_owned()))
+            .or_default();
+
+        if !playback_state.paused {
+            let frame_duration = 1.0 / GIF_FPS.load(Ordering::Relaxed).max(1.0);
+            playback_state.frame_timer += DELTA_TIME.load(Ordering::Relaxed);
+            while playback_state.frame_timer >= frame_duration {
+                playback_state.frame_timer -= frame_duration;
+                playback_state.current_frame += 1;
+            }
+        }
+
+        // Frames are stored counting from 1, see `DX11UI::load_gif_frames`.
+        let frame_path = ozencstr!(image_path, ".frame_", playback_state.current_frame + 1);
+        if let Some(texture_id) = self.get_texture_id(&frame_path, config_dir_path) {
+            return Some(texture_id);
+        }
+
+        // Went past the last frame, start over.
+        playback_state.current_frame = 0;
+        self.get_texture_id(&ozencstr!(image_path, ".frame_1"), config_dir_path)
+    }
+
+    /// Pauses or resumes the GIF playback of the image widget `identifier` inside of `window`.
+    pub fn set_gif_paused(&self, window: String, identifier: String, paused: bool) {
+        self.gif_playback_states
+            .entry((window, identifier))
+            .or_default()
+            .paused = paused;
+    }
+
+    /// Jumps to `frame` in the GIF playback of the image widget `identifier` inside of `window`.
+    /// Frames start at 1, going past the last frame starts over from the first one.
+    pub fn set_gif_frame(&self, window: String, identifier: String, frame: usize) {
+        let mut playback_state = self
+            .gif_playback_states
+            .entry((window, identifier))
+            .or_default();
+        playback_state.current_frame = frame.saturating_sub(1);
+        playback_state.frame_timer = 0.0;
+    }
+
+    /// Decodes `bytes` into an image and schedules it for upload to the GPU.
+    /// Returns the synthetic path the image is cached under, usable in place of a file path.
+    /// The path contains `window_name`, so that windows using the same identifier don't replace
+    /// each other's images.
+    pub fn cache_image_from_bytes(
+        &self,
+        window_name: &str,
+        identifier: &str,
+        bytes: &[u8],
+    ) -> Option<String> {
+        let image = image::load_from_memory(bytes);
+        let Ok(image) = image else {
+            log!(
+                "[ERROR] Failed decoding image bytes for \"",
+                identifier,
+                "\", error: ",
+                image.unwrap_err()
+            );
+            return None;
+        };
+
+        let Ok(mut cached_textures) = self.cached_images.try_borrow_mut() else {
+            log!("[ERROR] Cached textures is in use, cannot cache image from bytes!");
+            return None;
+        };
+
+        let image_path = ozencstr!("memory://", window_name, "/", identifier);
+        self.pending_memory_images
+            .insert(image_path.to_owned(), image.to_rgba8());
+        let replaced_texture = cached_textures.insert(
+            image_path.to_owned(),
+            CustomTexture {
+                texture_id: None,
+                texture_type: CustomTextureType::Memory,
+                size: [0, 0],
+            },
+        );
+        self.free_texture_ids(replaced_texture);
+
+        log!(
+            "[Texture Loader] Scheduled in-memory image \"",
+            image_path,
+            "\" for upload to the GPU!"
+        );
+        Some(image_path)
+    }
+
+    /// Takes the decoded in-memory image at `image_path`, if any.
+    pub fn take_pending_memory_image(&self, image_path: &str) -> Option<image::RgbaImage> {
+        self.pending_memory_images
+            .remove(image_path)
+            .map(|(_, image)| image)
     }
 
     /// Gets the Texture ID for an image. If it hasn't been cached already, then it's cached and
     /// returned on the next call.
     pub fn get_texture_id(&self, image_path: &str, config_dir_path: &str) -> Option<TextureId> {
+        // In-memory images are scheduled by `cache_image_from_bytes`, never by lookups.
+        if image_path.starts_with(&zencstr!("memory://").data) {
+            return if let Ok(cached_textures) = self.cached_images.try_borrow() {
+                cached_textures.get(image_path)?.texture_id
+            } else {
+                log!("[ERROR] Cached textures is locked, cannot get TextureId!");
+                None
+            };
+        }
+
         let mut full_image_path = ZString::new(String::with_capacity(
             config_dir_path.len() + image_path.len(),
         ));
@@ -984,6 +2195,7 @@ impl CustomWindowsUtils {
                 CustomTexture {
                     texture_id: None,
                     texture_type,
+                    size: [0, 0],
                 },
             );
             log!(
@@ -997,6 +2209,34 @@ impl CustomWindowsUtils {
         cached_texture.texture_id
     }
 
+    /// Sets the tooltip shown when hovering the widget `identifier` inside of `window`.
+    pub fn set_widget_tooltip(&self, window: &str, identifier: String, text: String) {
+        if self.get_widget(window, &identifier).is_none() {
+            return;
+        }
+
+        let Ok(mut widget_tooltips) = self.widget_tooltips.try_borrow_mut() else {
+            log!("[ERROR] Widget tooltips is in use, cannot set tooltip!");
+            return;
+        };
+
+        widget_tooltips.insert((window.to_owned(), identifier), text);
+    }
+
+    /// Sets the text color of the widget at `identifier` inside of `window`.
+    pub fn set_widget_text_color(&self, window: &str, identifier: String, color: [f32; 4]) {
+        if self.get_widget(window, &identifier).is_none() {
+            return;
+        }
+
+        let Ok(mut widget_text_colors) = self.widget_text_colors.try_borrow_mut() else {
+            log!("[ERROR] Widget text colors is in use, cannot set text color!");
+            return;
+        };
+
+        widget_text_colors.insert((window.to_owned(), identifier), color);
+    }
+
     /// Sets the UI Color preset for the focused window.
     pub fn set_color_preset_for(&self, window: String, preset: String) {
         self.window_color_presets.insert(window, preset);
@@ -1028,6 +2268,31 @@ impl CustomWindowsUtils {
         }
     }
 
+    /// Disables a set of widgets inside of `window` by their identifiers, keeping them visible
+    /// but greyed-out.
+    pub fn set_widgets_disabled(&self, window: &str, identifiers: Vec<String>) {
+        let Ok(mut disabled_widgets) = self.disabled_widgets.try_borrow_mut() else {
+            log!("[ERROR] Disabled widgets is already being borrowed, cannot insert new ones at this time!");
+            return;
+        };
+
+        for identifier in identifiers {
+            disabled_widgets.insert((window.to_owned(), identifier));
+        }
+    }
+
+    /// If the defined widgets inside of `window` are disabled, they'll then be enabled again.
+    pub fn set_widgets_enabled(&self, window: &str, identifiers: Vec<String>) {
+        let Ok(mut disabled_widgets) = self.disabled_widgets.try_borrow_mut() else {
+            log!("[ERROR] Disabled widgets is already being borrowed, cannot remove existing ones at this time!");
+            return;
+        };
+
+        for identifier in identifiers {
+            disabled_widgets.remove(&(window.to_owned(), identifier));
+        }
+    }
+
     /// Sets the name of the sub-widget to be used for adding all upcoming widgets, until set to
     /// `None` again.
     pub fn set_sub_widget_identifier(&self, focus: Option<String>) {
@@ -1058,8 +2323,160 @@ impl CustomWindowsUtils {
         window_widgets.retain(|identifier, _| identifiers.contains(identifier));
     }
 
+    /// Moves the widget named `identifier` to `new_index` in the window, changing the order in
+    /// which widgets are drawn.
+    /// `new_index` is clamped to the amount of widgets in the window.
+    pub fn move_widget(&self, window: &str, identifier: &str, new_index: usize) {
+        let window_widgets = self.window_widgets.try_get_mut(window);
+        if window_widgets.is_locked() {
+            log!(
+                "[ERROR] Window \"",
+                window,
+                "\" is locked, cannot move widgets!"
+            );
+            return;
+        }
+
+        let Some(mut window_widgets) = window_widgets.try_unwrap() else {
+            return;
+        };
+
+        let Some(index) = window_widgets.get_index_of(identifier) else {
+            log!(
+                "[ERROR] There is no widget named \"",
+                identifier,
+                "\" in \"",
+                window,
+                "\", cannot move it!"
+            );
+            return;
+        };
+
+        let new_index = new_index.min(window_widgets.len() - 1);
+        window_widgets.move_index(index, new_index);
+    }
+
     /// Gets the value of `self.cached_images`.
     pub const fn get_cached_images(&self) -> &AtomicRefCell<HashMap<String, CustomTexture>> {
         &self.cached_images
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rune::{Context, Vm};
+
+    /// Compiles an empty Rune function taking the optional parameter, to use as a widget
+    /// callback.
+    fn empty_callback() -> Rc<SyncFunction> {
+        let context = Context::with_default_modules().unwrap();
+        let runtime = Arc::new(context.runtime().unwrap());
+        let mut sources = rune::sources!(entry => { pub fn main(_opt_param) {} });
+        let unit = rune::prepare(&mut sources)
+            .with_context(&context)
+            .build()
+            .unwrap();
+
+        let vm = Vm::new(runtime, Arc::new(unit));
+        let main = vm.lookup_function(["main"]).into_result().unwrap();
+        Rc::new(main.into_sync().into_result().unwrap())
+    }
+
+    /// Wraps `widgets` inside of a collapsing header.
+    fn collapsing_header(widgets: WidgetsMap) -> WidgetType {
+        WidgetType::SubWidget(
+            SubWidgetType::CollapsingHeader(ZString::new("Header".to_owned())),
+            widgets,
+            empty_callback(),
+            Rc::new(None),
+        )
+    }
+
+    #[test]
+    fn finds_widgets_nested_in_sub_widgets() {
+        let mut inner_widgets = WidgetsMap::new();
+        inner_widgets.insert(
+            "separator".to_owned(),
+            Arc::new(AtomicRefCell::new(WidgetType::Separator)),
+        );
+
+        let mut outer_widgets = WidgetsMap::new();
+        outer_widgets.insert(
+            "inner_header".to_owned(),
+            Arc::new(AtomicRefCell::new(collapsing_header(inner_widgets))),
+        );
+
+        let mut window_widgets = WidgetsMap::new();
+        window_widgets.insert(
+            "same_line".to_owned(),
+            Arc::new(AtomicRefCell::new(WidgetType::SameLine)),
+        );
+        window_widgets.insert(
+            "outer_header".to_owned(),
+            Arc::new(AtomicRefCell::new(collapsing_header(outer_widgets))),
+        );
+
+        let same_line = CustomWindowsUtils::find_widget_in(&window_widgets, "same_line").unwrap();
+        assert!(matches!(*same_line.borrow(), WidgetType::SameLine));
+
+        let inner_header =
+            CustomWindowsUtils::find_widget_in(&window_widgets, "inner_header").unwrap();
+        assert!(matches!(*inner_header.borrow(), WidgetType::SubWidget(..)));
+
+        let separator = CustomWindowsUtils::find_widget_in(&window_widgets, "separator").unwrap();
+        assert!(matches!(*separator.borrow(), WidgetType::Separator));
+
+        assert!(CustomWindowsUtils::find_widget_in(&window_widgets, "missing").is_none());
+    }
+
+    #[test]
+    fn adds_and_removes_buttons_in_nested_collapsing_headers() {
+        let custom_window_utils: &'static CustomWindowsUtils = Box::leak(Box::default());
+        custom_window_utils.add_window("window".to_owned());
+        custom_window_utils.add_widget(
+            "window",
+            "outer_header".to_owned(),
+            collapsing_header(WidgetsMap::new()),
+        );
+
+        custom_window_utils.set_sub_widget_identifier(Some("outer_header".to_owned()));
+        custom_window_utils.add_widget(
+            "window",
+            "inner_header".to_owned(),
+            collapsing_header(WidgetsMap::new()),
+        );
+
+        custom_window_utils.set_sub_widget_identifier(Some("inner_header".to_owned()));
+        custom_window_utils.add_widget(
+            "window",
+            "button".to_owned(),
+            WidgetType::Button(
+                ZString::new("Button".to_owned()),
+                empty_callback(),
+                Rc::new(None),
+            ),
+        );
+        custom_window_utils.set_sub_widget_identifier(None);
+
+        // The button lives inside of the inner header, not at the top-level of the window.
+        let inner_header = custom_window_utils
+            .get_widget("window", "inner_header")
+            .unwrap();
+        let inner_header = inner_header.borrow();
+        let WidgetType::SubWidget(_, inner_widgets, ..) = &*inner_header else {
+            panic!("inner_header isn't a sub-widget!");
+        };
+        assert!(inner_widgets.contains_key("button"));
+        drop(inner_header);
+
+        let button = custom_window_utils.get_widget("window", "button").unwrap();
+        assert!(matches!(*button.borrow(), WidgetType::Button(..)));
+
+        custom_window_utils.remove_widget("window", "button".to_owned());
+        assert!(custom_window_utils.get_widget("window", "button").is_none());
+        assert!(custom_window_utils
+            .get_widget("window", "inner_header")
+            .is_some());
+    }
+}
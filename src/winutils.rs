@@ -1,31 +1,52 @@
 use crate::{
-    globals::{SafeMODULEENTRY32, LOGGED_MESSAGES, MODULES},
+    globals::{
+        SafeMODULEENTRY32, IS_UNLOADING, LOGGED_MESSAGES, LOG_FILE, LOG_TIMESTAMPS, MODULES,
+        SIDE_MESSAGE_MAX_LINES, WORKER_THREADS,
+    },
     utils::{
         extensions::{OptionExt, ResultExtensions},
+        generichooks::GenericHoooks,
         types::char_ptr,
     },
 };
 use ahash::AHashMap;
-use std::ffi::*;
+use std::{
+    ffi::*,
+    io::Write,
+    os::windows::io::AsRawHandle,
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
 use windows::{
     core::PCSTR,
     Win32::{
-        Foundation::{MAX_PATH, POINT},
+        Foundation::{HANDLE, MAX_PATH, POINT},
         Graphics::Gdi::ScreenToClient,
         System::{
-            Diagnostics::ToolHelp::MODULEENTRY32, LibraryLoader::*, Threading::GetCurrentProcess,
+            Diagnostics::ToolHelp::MODULEENTRY32,
+            LibraryLoader::*,
+            Memory::{
+                VirtualQuery, MEMORY_BASIC_INFORMATION, MEM_COMMIT, PAGE_GUARD, PAGE_NOACCESS,
+            },
+            SystemInformation::GetLocalTime,
+            Threading::GetCurrentProcess,
+            IO::CancelSynchronousIo,
         },
         UI::{
-            Input::KeyboardAndMouse::GetKeyState,
-            WindowsAndMessaging::{
-                GetCursorPos, GetForegroundWindow, MessageBoxA, MESSAGEBOX_STYLE,
+            Input::KeyboardAndMouse::{
+                GetAsyncKeyState, GetKeyState, SendInput, INPUT, INPUT_0, INPUT_MOUSE,
+                MOUSEEVENTF_MOVE, MOUSEINPUT,
             },
+            WindowsAndMessaging::{GetForegroundWindow, MessageBoxA, MESSAGEBOX_STYLE},
         },
     },
 };
 use wmem::Memory;
 use zstring::ZString;
 
+/// Is the next logged message the start of a new line?
+static IS_LOG_LINE_START: AtomicBool = AtomicBool::new(true);
+
 /// Renderer enum for determing the render target for an unsupported game.
 #[derive(Debug, Default)]
 pub enum Renderer {
@@ -95,6 +116,18 @@ impl WinUtils {
         Self::get_module(name).modBaseAddr
     }
 
+    /// Gets the size of a module in bytes.
+    pub fn get_module_size(name: &str) -> u32 {
+        Self::get_module(name).modBaseSize
+    }
+
+    /// Returns the names of all cached modules, sorted alphabetically.
+    pub fn list_modules() -> Vec<String> {
+        let mut modules: Vec<String> = Self::get_modules().keys().cloned().collect();
+        modules.sort_unstable();
+        modules
+    }
+
     /// Fetches the modules from the current process and returns them.
     /// This is the non-cache variant of `WinUtils::get_modules`.
     pub fn get_modules_no_cache() -> AHashMap<String, SafeMODULEENTRY32> {
@@ -141,6 +174,8 @@ impl WinUtils {
     }
 
     /// Finds an address by its signature, `0x7F` is for wildcards.
+    /// Kept for scripts relying on `0x7F` wildcards, new code should use
+    /// `find_from_masked_signature`.
     #[optimize(speed)]
     pub fn find_from_signature(
         module: &str,
@@ -155,6 +190,175 @@ impl WinUtils {
         ))
     }
 
+    /// Finds all addresses inside of `module` matching the signature.
+    /// Bytes in `sig` whose entry in `mask` is `false` are treated as wildcards.
+    pub fn find_from_masked_signature(module: &str, sig: &[u8], mask: &[bool]) -> Vec<usize> {
+        Self::scan_module(module, sig, mask, false)
+    }
+
+    /// Finds the first address inside of `module` matching the signature.
+    /// Bytes in `sig` whose entry in `mask` is `false` are treated as wildcards.
+    /// Unlike `find_from_masked_signature`, this stops scanning as soon as a match has been
+    /// found.
+    pub fn find_first_from_signature(module: &str, sig: &[u8], mask: &[bool]) -> Option<usize> {
+        Self::scan_module(module, sig, mask, true).first().copied()
+    }
+
+    /// Checks if every byte from `address` up to `address + len` is committed, readable memory.
+    pub fn is_range_readable(address: usize, len: usize) -> bool {
+        let Some(end) = address.checked_add(len) else {
+            return false;
+        };
+
+        let mut current_address = address;
+        let mut info = MEMORY_BASIC_INFORMATION::default();
+        while current_address < end {
+            let written = unsafe {
+                VirtualQuery(
+                    Some(current_address as _),
+                    &mut info,
+                    std::mem::size_of::<MEMORY_BASIC_INFORMATION>(),
+                )
+            };
+
+            if written == 0 || !Self::is_region_readable(&info) {
+                return false;
+            }
+
+            current_address = info.BaseAddress as usize + info.RegionSize;
+        }
+
+        true
+    }
+
+    /// Checks if the memory region described by `info` is committed and readable.
+    fn is_region_readable(info: &MEMORY_BASIC_INFORMATION) -> bool {
+        info.State == MEM_COMMIT
+            && info.Protect != PAGE_NOACCESS
+            && (info.Protect & PAGE_GUARD).0 == 0
+    }
+
+    /// Scans all readable memory regions of `module` for the masked signature.
+    /// Adjacent readable regions are scanned as one, so that matches spanning them are found.
+    /// If `first_only` is `true`, the scan stops after the first match.
+    #[optimize(speed)]
+    fn scan_module(module: &str, sig: &[u8], mask: &[bool], first_only: bool) -> Vec<usize> {
+        let mut matches = Vec::new();
+        if sig.is_empty() || sig.len() != mask.len() {
+            log!("[ERROR] Signature is empty or its mask doesn't match its length!");
+            return matches;
+        }
+
+        let module = Self::get_module(module);
+        let end = module.modBaseAddr as usize + module.modBaseSize as usize;
+        let mut address = module.modBaseAddr as usize;
+        let mut info = MEMORY_BASIC_INFORMATION::default();
+
+        // Start of the current run of adjacent readable regions, `None` if there is no run.
+        let mut run_start = None;
+
+        while address < end {
+            let written = unsafe {
+                VirtualQuery(
+                    Some(address as _),
+                    &mut info,
+                    std::mem::size_of::<MEMORY_BASIC_INFORMATION>(),
+                )
+            };
+
+            if written == 0 {
+                break;
+            }
+
+            let region_end = (info.BaseAddress as usize + info.RegionSize).min(end);
+            if Self::is_region_readable(&info) {
+                run_start.get_or_insert(address);
+            } else if let Some(start) = run_start.take()
+                && Self::scan_range(start, address, sig, mask, first_only, &mut matches)
+            {
+                return matches;
+            }
+
+            address = region_end;
+        }
+
+        if let Some(start) = run_start {
+            Self::scan_range(start, address, sig, mask, first_only, &mut matches);
+        }
+
+        matches
+    }
+
+    /// Scans the readable memory from `start` to `end` for the masked signature, pushing all
+    /// matches into `matches`.
+    /// Returns `true` if `first_only` is `true` and a match was found.
+    #[optimize(speed)]
+    fn scan_range(
+        start: usize,
+        end: usize,
+        sig: &[u8],
+        mask: &[bool],
+        first_only: bool,
+        matches: &mut Vec<usize>,
+    ) -> bool {
+        let range = unsafe { std::slice::from_raw_parts(start as *const u8, end - start) };
+        for (offset, window) in range.windows(sig.len()).enumerate() {
+            let is_match = window
+                .iter()
+                .zip(sig.iter().zip(mask))
+                .all(|(byte, (sig_byte, must_match))| !must_match || byte == sig_byte);
+
+            if !is_match {
+                continue;
+            }
+
+            matches.push(start + offset);
+            if first_only {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Spawns `worker` on a new thread, which is joined by `join_workers` before dynamic unloads.
+    /// Long-running workers have to return once `IS_UNLOADING` is `true`.
+    /// Nothing is spawned if dynamic is already unloading.
+    pub fn spawn_worker<F: FnOnce() + Send + 'static>(worker: F) {
+        let mut worker_threads = WORKER_THREADS.lock();
+        if IS_UNLOADING.load(Ordering::Relaxed) {
+            log!("[WARN] dynamic is unloading, no new threads can be spawned!");
+            return;
+        }
+
+        worker_threads.retain(|worker_thread| !worker_thread.is_finished());
+        worker_threads.push(std::thread::spawn(worker));
+    }
+
+    /// Joins all threads spawned through `spawn_worker`, including ones spawned while waiting.
+    /// Pending synchronous I/O like console reads is cancelled, so that blocked workers get to
+    /// check `IS_UNLOADING`.
+    pub fn join_workers() {
+        loop {
+            let worker_threads = std::mem::take(&mut *WORKER_THREADS.lock());
+            if worker_threads.is_empty() {
+                return;
+            }
+
+            for worker_thread in worker_threads {
+                while !worker_thread.is_finished() {
+                    let thread_handle = HANDLE(worker_thread.as_raw_handle() as isize);
+                    let _ = unsafe { CancelSynchronousIo(thread_handle) };
+                    std::thread::sleep(Duration::from_millis(10));
+                }
+
+                if worker_thread.join().is_err() {
+                    log!("[WARN] A worker thread panicked before dynamic was unloaded!");
+                }
+            }
+        }
+    }
+
     /// Checks if the given key is being held down.
     pub fn is_key_down(key: &str) -> bool {
         let Some(vkey) = Self::find_vkey_from_str(key) else {
@@ -165,6 +369,70 @@ impl WinUtils {
         unsafe { GetKeyState(vkey) < 0 }
     }
 
+    /// Injects a relative mouse movement through `SendInput`.
+    pub fn move_mouse(dx: i32, dy: i32) {
+        let input = INPUT {
+            r#type: INPUT_MOUSE,
+            Anonymous: INPUT_0 {
+                mi: MOUSEINPUT {
+                    dx,
+                    dy,
+                    dwFlags: MOUSEEVENTF_MOVE,
+                    ..Default::default()
+                },
+            },
+        };
+
+        if unsafe { SendInput(&[input], std::mem::size_of::<INPUT>() as i32) } == 0 {
+            log!("[ERROR] SendInput failed, mouse movement wasn't injected!");
+        }
+    }
+
+    /// Checks if the given virtual key code is being held down.
+    pub fn is_vkey_down(vkey: i64) -> bool {
+        if !(0x01..=0xFE).contains(&vkey) {
+            log!("[ERROR] Invalid virtual key code: ", vkey, "!");
+            return false;
+        }
+
+        unsafe { GetKeyState(vkey as i32) < 0 }
+    }
+
+    /// Checks if the given mouse button is being held down.
+    /// `0` is the left button, `1` the right button and `2` the middle button.
+    pub fn is_mouse_button_down(button: i64) -> bool {
+        let vkey = match button {
+            0 => 0x01,
+            1 => 0x02,
+            2 => 0x04,
+            _ => {
+                log!("[ERROR] Invalid mouse button: ", button, "!");
+                return false;
+            }
+        };
+
+        unsafe { GetKeyState(vkey) < 0 }
+    }
+
+    /// Checks if the given key went from being released to being held down since the last call.
+    pub fn was_key_pressed(key: &str) -> bool {
+        /// Previous down-state of each virtual key.
+        static PREVIOUS_KEY_STATES: [AtomicBool; 256] = [const { AtomicBool::new(false) }; 256];
+
+        let Some(vkey) = Self::find_vkey_from_str(key) else {
+            log!("[ERROR] Invalid key: \"", key, "\"!");
+            return false;
+        };
+
+        let Some(previous_state) = PREVIOUS_KEY_STATES.get(vkey as usize) else {
+            return false;
+        };
+
+        let is_down = unsafe { GetAsyncKeyState(vkey) < 0 };
+        let was_down = previous_state.swap(is_down, Ordering::Relaxed);
+        is_down && !was_down
+    }
+
     /// Parses a hexadecimal value to its normal primitive value.
     pub fn hex_to_primitive(hex: &str) -> i64 {
         i64::from_str_radix(&hex[2..], 16).unwrap_or_else(|error| {
@@ -193,11 +461,30 @@ impl WinUtils {
         }
     }
 
+    /// Gets the address to a function inside of a module, by its ordinal rather than its name.
+    /// Useful for modules which only export their functions by ordinal.
+    pub fn get_symbol_by_ordinal<S: AsRef<str>>(module: S, ordinal: u16) -> Option<usize> {
+        unsafe {
+            GetModuleHandleA(PCSTR(
+                CString::new(module.as_ref())
+                    .dynamic_expect(zencstr!("Failed constructing C-String"))
+                    .as_ref()
+                    .as_ptr() as _,
+            ))
+            .ok()
+            // Same as MAKEINTRESOURCEA, the ordinal is passed in the low-order word of the
+            // pointer.
+            .and_then(|handle| GetProcAddress(handle, PCSTR(ordinal as usize as _)))
+            .map(|result| result as usize)
+        }
+    }
+
     /// Returns the cursor position within the foreground window.
     /// This requires an already-made `POINT` instance, as it will output the data to it.
     pub fn get_cursor_pos_recycle(point: &mut POINT) {
         unsafe {
-            let cursor_pos = GetCursorPos(point);
+            // Bypasses the frozen position, as the software cursor has to keep moving.
+            let cursor_pos = GenericHoooks::get_real_cursor_pos(point);
             if let Err(error) = cursor_pos {
                 log!(
                     "[ERROR] Failed to call GetCursorPos, initial value in point remains. Error: ",
@@ -227,6 +514,12 @@ impl WinUtils {
 
     /// Displays a message box.
     pub fn display_message_box(caption: &str, text: &str, message_type: u32) {
+        Self::message_box(caption, text, message_type);
+    }
+
+    /// Displays a message box and blocks until it's closed.
+    /// Returns the ID of the button that was pressed, for example `IDYES` (`6`).
+    pub fn message_box(caption: &str, text: &str, message_type: u32) -> i32 {
         let text_cstr =
             CString::new(text).dynamic_expect(zencstr!("Failed creating C-String out of text"));
 
@@ -240,7 +533,8 @@ impl WinUtils {
                 PCSTR(caption_cstr.as_ptr() as _),
                 MESSAGEBOX_STYLE(message_type),
             )
-        };
+        }
+        .0
     }
 
     /// To be moved to general utils: Logs a message to `LOGGED_MESSAGES` and `stdout`.
@@ -253,7 +547,14 @@ impl WinUtils {
         };
 
         message.use_string(|message| {
-            if logged_messages.data.lines().count() >= 30 {
+            let is_line_start = IS_LOG_LINE_START.load(Ordering::Relaxed);
+            if is_line_start && LOG_TIMESTAMPS.load(Ordering::Relaxed) {
+                message.insert_str(0, &format!("[{}] ", Self::get_timestamp()));
+            }
+
+            if logged_messages.data.lines().count()
+                >= SIDE_MESSAGE_MAX_LINES.load(Ordering::Relaxed)
+            {
                 logged_messages.data.clear();
             }
 
@@ -269,6 +570,8 @@ impl WinUtils {
             }
 
             logged_messages.data.push_str(message);
+            Self::write_to_log_file(message, is_line_start);
+            IS_LOG_LINE_START.store(message.ends_with('\n'), Ordering::Relaxed);
 
             if new_line {
                 return;
@@ -278,6 +581,41 @@ impl WinUtils {
         });
     }
 
+    /// Appends `message` to the log file from config.jsonc -> log_file, if any.
+    /// Messages starting on a new line are prefixed with the current time, unless `message`
+    /// already has a timestamp from config.jsonc -> log_timestamps.
+    /// Write failures are ignored, as logging them would end up calling this again.
+    fn write_to_log_file(message: &str, is_line_start: bool) {
+        let Some(mut log_file) = LOG_FILE.get().map(|log_file| log_file.lock()) else {
+            return;
+        };
+
+        if is_line_start && !LOG_TIMESTAMPS.load(Ordering::Relaxed) {
+            let _ = write!(log_file, "[{}] ", Self::get_timestamp());
+        }
+
+        let _ = log_file.write_all(message.as_bytes());
+        if message.ends_with('\n') {
+            let _ = log_file.flush();
+        }
+    }
+
+    /// Returns the current local time as `HH:MM:SS`.
+    pub fn get_timestamp() -> String {
+        let time = unsafe { GetLocalTime() };
+        format!("{:02}:{:02}:{:02}", time.wHour, time.wMinute, time.wSecond)
+    }
+
+    /// Clears all logged screen messages.
+    pub fn clear_log() {
+        let Ok(mut logged_messages) = LOGGED_MESSAGES.try_borrow_mut() else {
+            log!("[ERROR] Logged messages are in use, cannot clear!");
+            return;
+        };
+
+        logged_messages.data.clear();
+    }
+
     /// Tries to find the virtual key code from the string.
     /// Only a limited set of keys are supported.
     pub fn find_vkey_from_str(str: &str) -> Option<i32> {
@@ -314,6 +652,43 @@ impl WinUtils {
             "LMButton" => 0x01,
             "RMButton" => 0x02,
             "MMButton" => 0x04,
+            "Tab" => 0x09,
+            "Enter" => 0x0D,
+            "Escape" => 0x1B,
+            "Backspace" => 0x08,
+            "Insert" => 0x2D,
+            "Delete" => 0x2E,
+            "Home" => 0x24,
+            "End" => 0x23,
+            "PageUp" => 0x21,
+            "PageDown" => 0x22,
+            "CapsLock" => 0x14,
+            "Numpad0" => 0x60,
+            "Numpad1" => 0x61,
+            "Numpad2" => 0x62,
+            "Numpad3" => 0x63,
+            "Numpad4" => 0x64,
+            "Numpad5" => 0x65,
+            "Numpad6" => 0x66,
+            "Numpad7" => 0x67,
+            "Numpad8" => 0x68,
+            "Numpad9" => 0x69,
+            "Multiply" => 0x6A,
+            "Add" => 0x6B,
+            "Subtract" => 0x6D,
+            "Decimal" => 0x6E,
+            "Divide" => 0x6F,
+            "[" => 0xDB,
+            "]" => 0xDD,
+            ";" => 0xBA,
+            "=" => 0xBB,
+            "," => 0xBC,
+            "-" => 0xBD,
+            "." => 0xBE,
+            "/" => 0xBF,
+            "`" => 0xC0,
+            "\\" => 0xDC,
+            "'" => 0xDE,
             _ => 0,
         };
 